@@ -1,11 +1,7 @@
-use crate::{Interval, SingleObjective};
+use crate::{interval, Differentiable, GlobalOptimum, Interval, Objective, Optimum};
 use std::f64::consts::{E, PI};
 use std::num::NonZeroUsize;
 
-const fn interval(low: f64, high: f64) -> Interval {
-    unsafe { Interval::new_unchecked(low, high) }
-}
-
 /// Ackley Function.
 ///
 /// # References
@@ -24,7 +20,9 @@ impl Ackley {
         Self { input_domain }
     }
 }
-impl SingleObjective for Ackley {
+impl Objective for Ackley {
+    type Output = f64;
+
     fn input_domain(&self) -> &[Interval] {
         &self.input_domain
     }
@@ -43,6 +41,33 @@ impl SingleObjective for Ackley {
         -A * temp0.exp() - temp1.exp() + A + E
     }
 }
+impl GlobalOptimum for Ackley {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0; self.dimension().get()]]))
+    }
+}
+impl Differentiable for Ackley {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        const A: f64 = 20.0;
+        const B: f64 = 0.2;
+        const C: f64 = 2.0 * PI;
+
+        let n = xs.len() as f64;
+
+        let r = (xs.iter().map(|&x| x * x).sum::<f64>() / n).sqrt();
+        let temp0 = if r == 0.0 {
+            0.0
+        } else {
+            A * B * (-B * r).exp() / (n * r)
+        };
+        let temp1 = (xs.iter().map(|&x| (C * x).cos()).sum::<f64>() / n).exp() * C / n;
+        xs.iter()
+            .map(|&x| temp0 * x + temp1 * (C * x).sin())
+            .collect()
+    }
+}
 
 /// Ackley N. 2 Function.
 ///
@@ -51,7 +76,9 @@ impl SingleObjective for Ackley {
 /// - [BenchmarkFcns: Ackley N. 2 Function](http://http://benchmarkfcns.xyz/benchmarkfcns/ackleyn2fcn.html)
 #[derive(Debug, Clone)]
 pub struct AckleyN2;
-impl SingleObjective for AckleyN2 {
+impl Objective for AckleyN2 {
+    type Output = f64;
+
     fn input_domain(&self) -> &[Interval] {
         const I: Interval = interval(-32.0, 32.0);
         &[I, I]
@@ -63,6 +90,23 @@ impl SingleObjective for AckleyN2 {
         -200.0 * (-0.2 * (xs[0].powi(2) + xs[1].powi(2)).sqrt()).exp()
     }
 }
+impl GlobalOptimum for AckleyN2 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(-200.0, vec![vec![0.0, 0.0]]))
+    }
+}
+impl Differentiable for AckleyN2 {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let r = (xs[0].powi(2) + xs[1].powi(2)).sqrt();
+        if r == 0.0 {
+            return vec![0.0, 0.0];
+        }
+        let temp = 40.0 * (-0.2 * r).exp() / r;
+        vec![temp * xs[0], temp * xs[1]]
+    }
+}
 
 /// Ackley N. 3 Function.
 ///
@@ -71,7 +115,9 @@ impl SingleObjective for AckleyN2 {
 /// - [BenchmarkFcns: Ackley N. 3 Function](http://http://benchmarkfcns.xyz/benchmarkfcns/ackleyn3fcn.html)
 #[derive(Debug, Clone)]
 pub struct AckleyN3;
-impl SingleObjective for AckleyN3 {
+impl Objective for AckleyN3 {
+    type Output = f64;
+
     fn input_domain(&self) -> &[Interval] {
         const I: Interval = interval(-32.0, 32.0);
         &[I, I]
@@ -83,6 +129,25 @@ impl SingleObjective for AckleyN3 {
         AckleyN2.evaluate(xs) + 5.0 * ((3.0 * xs[0]).cos() + (3.0 * xs[1]).sin()).exp()
     }
 }
+impl GlobalOptimum for AckleyN3 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            -186.41121271126887,
+            vec![vec![0.0, -0.006773454499845035]],
+        ))
+    }
+}
+impl Differentiable for AckleyN3 {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let mut g = AckleyN2.gradient(xs);
+        let temp = 5.0 * ((3.0 * xs[0]).cos() + (3.0 * xs[1]).sin()).exp();
+        g[0] -= temp * 3.0 * (3.0 * xs[0]).sin();
+        g[1] += temp * 3.0 * (3.0 * xs[1]).cos();
+        g
+    }
+}
 
 /// Ackley N. 4 Function.
 ///
@@ -102,7 +167,9 @@ impl AckleyN4 {
         Self { input_domain }
     }
 }
-impl SingleObjective for AckleyN4 {
+impl Objective for AckleyN4 {
+    type Output = f64;
+
     fn input_domain(&self) -> &[Interval] {
         &self.input_domain
     }
@@ -121,6 +188,40 @@ impl SingleObjective for AckleyN4 {
             .sum()
     }
 }
+impl GlobalOptimum for AckleyN4 {
+    /// The global optimum is only known for the 2-dimensional case.
+    fn global_optimum(&self) -> Option<Optimum> {
+        if self.dimension().get() != 2 {
+            return None;
+        }
+        Some(Optimum::new(
+            -4.590101634158667,
+            vec![
+                vec![-1.5096201081585965, -0.754865116036233],
+                vec![1.5096201081585965, -0.754865116036233],
+            ],
+        ))
+    }
+}
+impl Differentiable for AckleyN4 {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let mut g = vec![0.0; xs.len()];
+        for i in 0..xs.len() - 1 {
+            let x0 = xs[i];
+            let x1 = xs[i + 1];
+            let a = (x0 * x0 + x1 * x1).sqrt();
+            if a != 0.0 {
+                g[i] += (-0.2f64).exp() * x0 / a;
+                g[i + 1] += (-0.2f64).exp() * x1 / a;
+            }
+            g[i] -= 6.0 * (2.0 * x0).sin();
+            g[i + 1] += 6.0 * (2.0 * x1).cos();
+        }
+        g
+    }
+}
 
 /// Adjiman Function.
 ///
@@ -129,7 +230,9 @@ impl SingleObjective for AckleyN4 {
 /// - [BenchmarkFcns: Adjiman Function](http://http://benchmarkfcns.xyz/benchmarkfcns/adjimanfcn.html)
 #[derive(Debug, Clone)]
 pub struct Adjiman;
-impl SingleObjective for Adjiman {
+impl Objective for Adjiman {
+    type Output = f64;
+
     fn input_domain(&self) -> &[Interval] {
         const X: Interval = interval(-1.0, 2.0);
         const Y: Interval = interval(-1.0, 1.0);
@@ -142,10 +245,173 @@ impl SingleObjective for Adjiman {
         xs[0].cos() * xs[1].sin() - xs[0] / (xs[1].powi(2) + 1.0)
     }
 }
+impl GlobalOptimum for Adjiman {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            -2.021806783359787,
+            vec![vec![2.0, 0.1057834694517169]],
+        ))
+    }
+}
+impl Differentiable for Adjiman {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let d = y.powi(2) + 1.0;
+        vec![
+            -x.sin() * y.sin() - 1.0 / d,
+            x.cos() * y.cos() + 2.0 * x * y / d.powi(2),
+        ]
+    }
+}
+
+/// Alpine N. 1 Function.
+///
+/// # References
+///
+/// - [BenchmarkFcns: Alpine N. 1 Function](http://benchmarkfcns.xyz/benchmarkfcns/alpinen1fcn.html)
+#[derive(Debug, Clone)]
+pub struct AlpineN1 {
+    input_domain: Vec<Interval>,
+}
+impl AlpineN1 {
+    /// Makes a new `AlpineN1` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get())
+            .map(|_| interval(-10.0, 10.0))
+            .collect();
+        Self { input_domain }
+    }
+}
+impl Objective for AlpineN1 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        xs.iter().map(|&x| (x * x.sin() + 0.1 * x).abs()).sum()
+    }
+}
+impl GlobalOptimum for AlpineN1 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0; self.dimension().get()]]))
+    }
+}
+
+/// Alpine N. 2 Function.
+///
+/// # References
+///
+/// - [BenchmarkFcns: Alpine N. 2 Function](http://benchmarkfcns.xyz/benchmarkfcns/alpinen2fcn.html)
+#[derive(Debug, Clone)]
+pub struct AlpineN2 {
+    input_domain: Vec<Interval>,
+}
+impl AlpineN2 {
+    /// Makes a new `AlpineN2` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get()).map(|_| interval(0.0, 10.0)).collect();
+        Self { input_domain }
+    }
+}
+impl Objective for AlpineN2 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        -xs.iter().map(|&x| x.sqrt() * x.sin()).product::<f64>()
+    }
+}
+impl GlobalOptimum for AlpineN2 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        const X: f64 = 7.917052684666207;
+        const Y: f64 = 2.808131180007005;
+
+        let d = self.dimension().get();
+        Some(Optimum::new(-Y.powi(d as i32), vec![vec![X; d]]))
+    }
+}
+impl Differentiable for AlpineN2 {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        (0..xs.len())
+            .map(|i| {
+                -xs.iter()
+                    .enumerate()
+                    .map(|(j, &x)| {
+                        if i == j && x == 0.0 {
+                            // One-sided limit as `x` approaches zero from above.
+                            0.0
+                        } else if i == j {
+                            x.sin() / (2.0 * x.sqrt()) + x.sqrt() * x.cos()
+                        } else {
+                            x.sqrt() * x.sin()
+                        }
+                    })
+                    .product::<f64>()
+            })
+            .collect()
+    }
+}
+
+/// AMGM (Arithmetic Mean - Geometric Mean Equality) Function.
+///
+/// # References
+///
+/// - [Global Optimization Benchmarks: AMGM](http://infinity77.net/global_optimization/test_functions_nd_A.html#go_benchmark.AMGM)
+#[derive(Debug, Clone)]
+pub struct Amgm {
+    input_domain: Vec<Interval>,
+}
+impl Amgm {
+    /// Makes a new `Amgm` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get()).map(|_| interval(0.0, 10.0)).collect();
+        Self { input_domain }
+    }
+}
+impl Objective for Amgm {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let n = xs.len() as f64;
+        let arithmetic_mean = xs.iter().sum::<f64>() / n;
+        let geometric_mean = xs.iter().product::<f64>().powf(1.0 / n);
+        (arithmetic_mean - geometric_mean).powi(2)
+    }
+}
+impl GlobalOptimum for Amgm {
+    /// Returns the endpoints `(0, ..., 0)` and `(10, ..., 10)` of the diagonal of the input domain.
+    ///
+    /// Every input on the diagonal (i.e., whose elements are all equal) attains the optimum value,
+    /// so the optimum inputs are the line segment between the two reported inputs.
+    fn global_optimum(&self) -> Option<Optimum> {
+        let d = self.dimension().get();
+        Some(Optimum::new(0.0, vec![vec![0.0; d], vec![10.0; d]]))
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::{assert_global_optimum, assert_gradient, assert_reference_values};
 
     #[test]
     fn ackley_1dim_works() {
@@ -205,6 +471,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn global_optimums_work() {
+        let d = NonZeroUsize::new(5).unwrap();
+        assert_global_optimum(&Ackley::new(d));
+        assert_global_optimum(&AckleyN2);
+        assert_global_optimum(&AckleyN3);
+        assert_global_optimum(&AckleyN4::new(NonZeroUsize::new(2).unwrap()));
+        assert_global_optimum(&Adjiman);
+        assert_global_optimum(&AlpineN1::new(d));
+        assert_global_optimum(&AlpineN2::new(d));
+        assert_global_optimum(&Amgm::new(d));
+        assert!(AckleyN4::new(d).global_optimum().is_none());
+    }
+
+    #[test]
+    fn gradients_work() {
+        let xs = [0.3, -1.2, 2.5];
+        assert_gradient(&Ackley::new(NonZeroUsize::new(3).unwrap()), &xs);
+        assert_gradient(&AckleyN4::new(NonZeroUsize::new(3).unwrap()), &xs);
+        for xs in &[[0.3, -1.2], [1.7, 0.4], [-0.5, 0.9]] {
+            assert_gradient(&AckleyN2, xs);
+            assert_gradient(&AckleyN3, xs);
+            assert_gradient(&Adjiman, xs);
+        }
+        assert_gradient(
+            &AlpineN2::new(NonZeroUsize::new(3).unwrap()),
+            &[0.3, 1.2, 2.5],
+        );
+    }
+
     #[test]
     fn ackley_n2_works() {
         let global_minimum = -200.0;
@@ -252,4 +548,71 @@ mod tests {
             assert_eq!(f.evaluate(x), *y);
         }
     }
+
+    #[test]
+    fn alpine_n1_works() {
+        let xs = [
+            [-7.312715117751976, 6.9486747387446535, 5.275492379532281],
+            [-4.898619485211566, -0.09129825816118142, -1.010178704225238],
+            [3.031859454455258, 5.7744670227102635, -8.122808264515303],
+            [-9.433050469559873, 6.715302078397393, -1.344658641898933],
+            [5.245601649158839, -9.957878932977787, -1.092256118903972],
+        ];
+        let ys = [
+            14.454847910069,
+            6.059115838633843,
+            9.889051536773641,
+            5.681154470742875,
+            10.909620689831801,
+        ];
+
+        let f = AlpineN1::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn alpine_n2_works() {
+        let xs = [
+            [7.215400323407826, 2.2876222127045267, 9.452706955539224],
+            [9.014274576114836, 0.30589983033553536, 0.254458609934608],
+            [5.414124727934966, 9.391491627785106, 3.8120423768821246],
+            [2.1659939713061336, 4.221165755827173, 0.29040787574867943],
+            [2.2169166627303505, 4.378875936505721, 4.958122413818506],
+        ];
+        let ys = [
+            0.21115160634158964,
+            -0.02534071133824177,
+            -0.21986698383372177,
+            0.34067043845744144,
+            -5.076771152548718,
+        ];
+
+        let f = AlpineN2::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+        assert!((f.global_optimum().unwrap().value() + 22.143801266508344).abs() < 1e-12);
+        assert_eq!(f.gradient(&[0.0, 1.0, 2.0]), vec![0.0; 3]);
+        assert_eq!(f.gradient(&[0.0; 3]), vec![0.0; 3]);
+    }
+
+    #[test]
+    fn amgm_works() {
+        let xs = [
+            [2.3308445025757263, 2.308665415409843, 2.187810373376886],
+            [4.596034657377336, 2.897816145904856, 0.21489705265908876],
+            [8.375779756625729, 5.564543226524334, 6.422943629324456],
+            [1.859062658947177, 9.92543412176065, 8.5994652879529],
+            [1.2088995980580641, 3.326951853601291, 7.2148440758326835],
+        ];
+        let ys = [
+            7.719246807198356e-7,
+            1.321992385977102,
+            0.009646175788072284,
+            1.9066618582563544,
+            0.7122601646434246,
+        ];
+
+        let f = Amgm::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+        assert_eq!(f.evaluate(&[4.2, 4.2, 4.2]), 0.0);
+    }
 }
@@ -0,0 +1,270 @@
+use crate::{interval, Differentiable, GlobalOptimum, Interval, Objective, Optimum};
+use std::f64::consts::PI;
+
+/// Beale Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Beale Function](https://www.sfu.ca/~ssurjano/beale.html)
+#[derive(Debug, Clone)]
+pub struct Beale;
+impl Objective for Beale {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-4.5, 4.5);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        (1.5 - x + x * y).powi(2)
+            + (2.25 - x + x * y.powi(2)).powi(2)
+            + (2.625 - x + x * y.powi(3)).powi(2)
+    }
+}
+impl GlobalOptimum for Beale {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![3.0, 0.5]]))
+    }
+}
+impl Differentiable for Beale {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let t1 = 1.5 - x + x * y;
+        let t2 = 2.25 - x + x * y.powi(2);
+        let t3 = 2.625 - x + x * y.powi(3);
+        vec![
+            2.0 * t1 * (y - 1.0) + 2.0 * t2 * (y.powi(2) - 1.0) + 2.0 * t3 * (y.powi(3) - 1.0),
+            2.0 * t1 * x + 4.0 * t2 * x * y + 6.0 * t3 * x * y.powi(2),
+        ]
+    }
+}
+
+/// Booth Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Booth Function](https://www.sfu.ca/~ssurjano/booth.html)
+#[derive(Debug, Clone)]
+pub struct Booth;
+impl Objective for Booth {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-10.0, 10.0);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        (x + 2.0 * y - 7.0).powi(2) + (2.0 * x + y - 5.0).powi(2)
+    }
+}
+impl GlobalOptimum for Booth {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![1.0, 3.0]]))
+    }
+}
+impl Differentiable for Booth {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let a = x + 2.0 * y - 7.0;
+        let b = 2.0 * x + y - 5.0;
+        vec![2.0 * a + 4.0 * b, 4.0 * a + 2.0 * b]
+    }
+}
+
+/// Branin Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Branin Function](https://www.sfu.ca/~ssurjano/branin.html)
+#[derive(Debug, Clone)]
+pub struct Branin;
+impl Branin {
+    const B: f64 = 5.1 / (4.0 * PI * PI);
+    const C: f64 = 5.0 / PI;
+    const T: f64 = 1.0 / (8.0 * PI);
+}
+impl Objective for Branin {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-5.0, 10.0);
+        const Y: Interval = interval(0.0, 15.0);
+        &[X, Y]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let u = y - Self::B * x.powi(2) + Self::C * x - 6.0;
+        u.powi(2) + 10.0 * (1.0 - Self::T) * x.cos() + 10.0
+    }
+}
+impl GlobalOptimum for Branin {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            10.0 * Self::T,
+            vec![vec![-PI, 12.275], vec![PI, 2.275], vec![3.0 * PI, 2.475]],
+        ))
+    }
+}
+impl Differentiable for Branin {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let u = y - Self::B * x.powi(2) + Self::C * x - 6.0;
+        vec![
+            2.0 * u * (-2.0 * Self::B * x + Self::C) - 10.0 * (1.0 - Self::T) * x.sin(),
+            2.0 * u,
+        ]
+    }
+}
+
+/// Bukin Function N. 6.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Bukin Function N. 6](https://www.sfu.ca/~ssurjano/bukin6.html)
+#[derive(Debug, Clone)]
+pub struct BukinN6;
+impl Objective for BukinN6 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-15.0, -5.0);
+        const Y: Interval = interval(-3.0, 3.0);
+        &[X, Y]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        100.0 * (y - 0.01 * x.powi(2)).abs().sqrt() + 0.01 * (x + 10.0).abs()
+    }
+}
+impl GlobalOptimum for BukinN6 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![-10.0, 1.0]]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_global_optimum, assert_gradient, assert_reference_values};
+
+    #[test]
+    fn beale_works() {
+        let xs = [
+            [-2.375567192363089, -3.5715056919235577],
+            [-0.935475816503871, -3.1052495627783077],
+            [-3.901364138883691, -0.8856808696343266],
+            [3.7615953877894697, 2.7040711634622765],
+            [2.386463422548946, -2.5026464187871413],
+        ];
+        let ys = [
+            13631.719381204051,
+            1059.2972806762973,
+            173.3155265872115,
+            6102.064751784072,
+            1647.8939176570357,
+        ];
+
+        let f = Beale;
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn booth_works() {
+        let xs = [
+            [0.7336001634962699, -4.466347131170996],
+            [-6.546709414292621, -7.876334151369397],
+            [-5.71199134842167, 8.54951262856121],
+            [6.578400975568389, 6.133046934046469],
+            [6.008956770859324, -6.131287639615199],
+        ];
+        let ys = [
+            294.9988110203652,
+            1532.8816044451398,
+            81.25334537236134,
+            344.49183926289265,
+            176.44450905571705,
+        ];
+
+        let f = Booth;
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn branin_works() {
+        let xs = [
+            [-0.3522506405069663, 9.404634036196951],
+            [5.97842063318077, 12.819725369870207],
+            [8.20076127041799, 1.3007737895238276],
+            [4.087778255798199, 10.0755218477471],
+            [2.5893066356562304, 2.666852613611767],
+        ];
+        let ys = [
+            27.010002854566146,
+            156.4577309024803,
+            6.848939651246791,
+            75.32772693137655,
+            1.8315822783008018,
+        ];
+
+        let f = Branin;
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn bukin_n6_works() {
+        let xs = [
+            [-10.264121124148549, -2.463922756407658],
+            [-5.65411636100092, 2.1929050211104997],
+            [-9.52361130412946, -1.198525562560052],
+            [-5.911297105853135, 0.4342008101153487],
+            [-6.1768276012121, 2.0882645293418083],
+        ];
+        let ys = [
+            187.55115709232177,
+            136.90889285364347,
+            145.1087709054238,
+            29.1555698159258,
+            130.6802057289321,
+        ];
+
+        let f = BukinN6;
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        assert_global_optimum(&Beale);
+        assert_global_optimum(&Booth);
+        assert_global_optimum(&Branin);
+        assert_global_optimum(&BukinN6);
+    }
+
+    #[test]
+    fn gradients_work() {
+        for xs in &[[0.3, -1.2], [1.7, 0.4], [-0.5, 0.9]] {
+            assert_gradient(&Beale, xs);
+            assert_gradient(&Booth, xs);
+            assert_gradient(&Branin, xs);
+        }
+    }
+}
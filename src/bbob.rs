@@ -0,0 +1,609 @@
+//! BBOB-style distortion transforms.
+//!
+//! # References
+//!
+//! - [Real-Parameter Black-Box Optimization Benchmarking 2009: Noiseless Functions Definitions](https://hal.inria.fr/inria-00362633)
+use crate::mfso::{self, MultiFidelitySingleObjective};
+use crate::transform::{self, Penalize};
+use crate::{
+    Differentiable, Error, GlobalOptimum, Interval, Objective, Optimum, Sense, SingleObjective,
+    StochasticObjective, VariableType,
+};
+
+/// Strictly increasing coordinate-wise map applied to inputs.
+pub trait InputMap {
+    /// Maps the `i`-th element `x` of a `dimension`-dimensional input.
+    fn map(&self, i: usize, dimension: usize, x: f64) -> f64;
+
+    /// Returns the derivative of `map` at `x`.
+    fn derivative(&self, i: usize, dimension: usize, x: f64) -> f64;
+
+    /// Returns `x` such that `map(i, dimension, x) == y`.
+    ///
+    /// The default implementation uses the bisection method,
+    /// which requires `map` to be a bijection onto the real line (e.g., it panics if `y` is out of the range of `map`).
+    fn inverse(&self, i: usize, dimension: usize, y: f64) -> f64 {
+        bisect(|x| self.map(i, dimension, x), y)
+    }
+}
+
+/// Strictly increasing map applied to outputs.
+pub trait OutputMap {
+    /// Maps the output `y`.
+    fn map(&self, y: f64) -> f64;
+
+    /// Returns the derivative of `map` at `y`.
+    fn derivative(&self, y: f64) -> f64;
+}
+
+/// Oscillation transform (`T_osz`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Oscillation;
+impl Oscillation {
+    fn oscillate(x: f64) -> f64 {
+        if x == 0.0 {
+            return 0.0;
+        }
+        let (c1, c2) = Self::coefficients(x);
+        let h = x.abs().ln();
+        x.signum() * (h + 0.049 * ((c1 * h).sin() + (c2 * h).sin())).exp()
+    }
+
+    fn oscillate_derivative(x: f64) -> f64 {
+        if x == 0.0 {
+            return 1.0;
+        }
+        let (c1, c2) = Self::coefficients(x);
+        let h = x.abs().ln();
+        let g = (h + 0.049 * ((c1 * h).sin() + (c2 * h).sin())).exp();
+        let dg = 1.0 + 0.049 * (c1 * (c1 * h).cos() + c2 * (c2 * h).cos());
+        g * dg / x.abs()
+    }
+
+    fn coefficients(x: f64) -> (f64, f64) {
+        if x > 0.0 {
+            (10.0, 7.9)
+        } else {
+            (5.5, 3.1)
+        }
+    }
+}
+impl InputMap for Oscillation {
+    fn map(&self, _i: usize, _dimension: usize, x: f64) -> f64 {
+        Self::oscillate(x)
+    }
+
+    fn derivative(&self, _i: usize, _dimension: usize, x: f64) -> f64 {
+        Self::oscillate_derivative(x)
+    }
+}
+impl OutputMap for Oscillation {
+    fn map(&self, y: f64) -> f64 {
+        Self::oscillate(y)
+    }
+
+    fn derivative(&self, y: f64) -> f64 {
+        Self::oscillate_derivative(y)
+    }
+}
+
+/// Asymmetry transform (`T_asy^beta`).
+#[derive(Debug, Clone, Copy)]
+pub struct Asymmetry {
+    beta: f64,
+}
+impl Asymmetry {
+    /// Makes a new `Asymmetry` instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `beta` is negative.
+    pub fn new(beta: f64) -> Self {
+        assert!(beta >= 0.0);
+        Self { beta }
+    }
+
+    fn exponent_factor(&self, i: usize, dimension: usize) -> f64 {
+        if dimension == 1 {
+            0.0
+        } else {
+            self.beta * i as f64 / (dimension - 1) as f64
+        }
+    }
+}
+impl InputMap for Asymmetry {
+    fn map(&self, i: usize, dimension: usize, x: f64) -> f64 {
+        if x > 0.0 {
+            x.powf(1.0 + self.exponent_factor(i, dimension) * x.sqrt())
+        } else {
+            x
+        }
+    }
+
+    fn derivative(&self, i: usize, dimension: usize, x: f64) -> f64 {
+        if x > 0.0 {
+            let k = self.exponent_factor(i, dimension);
+            let y = self.map(i, dimension, x);
+            y * (k * x.ln() / (2.0 * x.sqrt()) + (1.0 + k * x.sqrt()) / x)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Ill-conditioning transform (diagonal matrix `Λ^alpha`).
+#[derive(Debug, Clone, Copy)]
+pub struct IllConditioning {
+    alpha: f64,
+}
+impl IllConditioning {
+    /// Makes a new `IllConditioning` instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not positive.
+    pub fn new(alpha: f64) -> Self {
+        assert!(alpha > 0.0);
+        Self { alpha }
+    }
+
+    fn lambda(&self, i: usize, dimension: usize) -> f64 {
+        if dimension == 1 {
+            1.0
+        } else {
+            self.alpha.powf(0.5 * i as f64 / (dimension - 1) as f64)
+        }
+    }
+}
+impl InputMap for IllConditioning {
+    fn map(&self, i: usize, dimension: usize, x: f64) -> f64 {
+        self.lambda(i, dimension) * x
+    }
+
+    fn derivative(&self, i: usize, dimension: usize, _x: f64) -> f64 {
+        self.lambda(i, dimension)
+    }
+
+    fn inverse(&self, i: usize, dimension: usize, y: f64) -> f64 {
+        y / self.lambda(i, dimension)
+    }
+}
+
+/// Symmetric logarithmic transform (`sign(y) * ln(1 + |y|)`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Log;
+impl OutputMap for Log {
+    fn map(&self, y: f64) -> f64 {
+        y.signum() * y.abs().ln_1p()
+    }
+
+    fn derivative(&self, y: f64) -> f64 {
+        1.0 / (1.0 + y.abs())
+    }
+}
+
+/// Symmetric power transform (`sign(y) * |y|^exponent`).
+///
+/// If `exponent < 1`, the derivative at `y = 0` is infinite.
+/// `OutputDistorted` treats the gradient at such a point as zero wherever the inner gradient is zero.
+#[derive(Debug, Clone, Copy)]
+pub struct Power {
+    exponent: f64,
+}
+impl Power {
+    /// Makes a new `Power` instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exponent` is not positive.
+    pub fn new(exponent: f64) -> Self {
+        assert!(exponent > 0.0);
+        Self { exponent }
+    }
+}
+impl OutputMap for Power {
+    fn map(&self, y: f64) -> f64 {
+        y.signum() * y.abs().powf(self.exponent)
+    }
+
+    fn derivative(&self, y: f64) -> f64 {
+        self.exponent * y.abs().powf(self.exponent - 1.0)
+    }
+}
+
+/// Wrapper that distorts the inputs of an objective.
+///
+/// The output at `xs` is the output of the inner objective at `center + map(xs - center)`.
+/// Since `map` fixes the origin, `center` is fixed by this transform
+/// (`InputDistorted::new` sets it to the global optimum of the inner objective).
+/// Points mapped out of the input domain are penalized as described in the `transform` module documentation.
+#[derive(Debug, Clone)]
+pub struct InputDistorted<T, M> {
+    inner: T,
+    map: M,
+    center: Vec<f64>,
+}
+impl<T, M> InputDistorted<T, M>
+where
+    T: Objective,
+    M: InputMap,
+{
+    /// Makes a new `InputDistorted` instance whose center is the (first) global optimum of `inner`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the global optimum of `inner` is unknown.
+    pub fn new(inner: T, map: M) -> Self
+    where
+        T: GlobalOptimum,
+    {
+        let optimum = inner.global_optimum().unwrap_or_else(|| panic!());
+        let center = optimum.inputs()[0].clone();
+        Self::with_center(inner, map, center)
+    }
+
+    /// Makes a new `InputDistorted` instance with the given center.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `center` is different from the dimension of `inner`.
+    pub fn with_center(inner: T, map: M, center: Vec<f64>) -> Self {
+        assert_eq!(center.len(), inner.dimension().get());
+        Self { inner, map, center }
+    }
+
+    /// Returns the center of the transform.
+    pub fn center(&self) -> &[f64] {
+        &self.center
+    }
+
+    /// Returns a reference to the wrapped objective.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the wrapped objective.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    fn distort(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.center.len());
+        let d = xs.len();
+        xs.iter()
+            .zip(self.center.iter())
+            .enumerate()
+            .map(|(i, (x, c))| c + self.map.map(i, d, x - c))
+            .collect()
+    }
+}
+impl<T, M> Objective for InputDistorted<T, M>
+where
+    T: Objective,
+    T::Output: Penalize,
+    M: InputMap,
+{
+    type Output = T::Output;
+
+    fn input_domain(&self) -> &[Interval] {
+        self.inner.input_domain()
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> Self::Output {
+        transform::evaluate_clamped(&self.inner, &self.distort(xs), |zs| self.inner.evaluate(zs))
+    }
+
+    fn sense(&self) -> Sense {
+        self.inner.sense()
+    }
+    /// Returns the types of the input variables, whose feasible values are mapped by the inverse of the transform.
+    fn variable_types(&self) -> Vec<VariableType> {
+        let d = self.center.len();
+        self.inner
+            .variable_types()
+            .iter()
+            .zip(self.inner.input_domain().iter())
+            .zip(self.center.iter())
+            .enumerate()
+            .map(|(i, ((t, &domain), &c))| {
+                t.pull_back(domain, domain, |z| c + self.map.inverse(i, d, z - c))
+            })
+            .collect()
+    }
+
+    fn validate(&self, xs: &[f64]) -> Result<(), Error> {
+        crate::validate_domain(self.input_domain(), xs)?;
+        transform::validate_clamped(&self.inner, &self.distort(xs))
+            .map_err(|e| transform::off_grid_at(e, xs))
+    }
+}
+impl<T, M> MultiFidelitySingleObjective for InputDistorted<T, M>
+where
+    T: MultiFidelitySingleObjective,
+    M: InputMap,
+{
+    fn max_cost(&self) -> mfso::Cost {
+        self.inner.max_cost()
+    }
+}
+impl<T, M> StochasticObjective for InputDistorted<T, M>
+where
+    T: StochasticObjective,
+    T::Output: Penalize,
+    M: InputMap,
+{
+    fn evaluate_with_seed(&self, xs: &[f64], seed: u64) -> Self::Output {
+        transform::evaluate_clamped(&self.inner, &self.distort(xs), |zs| {
+            self.inner.evaluate_with_seed(zs, seed)
+        })
+    }
+}
+impl<T, M> Differentiable for InputDistorted<T, M>
+where
+    T: Differentiable,
+    M: InputMap,
+{
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        let d = xs.len();
+        transform::gradient_clamped(&self.inner, &self.distort(xs))
+            .into_iter()
+            .zip(xs.iter().zip(self.center.iter()))
+            .enumerate()
+            .map(|(i, (g, (x, c)))| g * self.map.derivative(i, d, x - c))
+            .collect()
+    }
+}
+impl<T, M> GlobalOptimum for InputDistorted<T, M>
+where
+    T: Objective + GlobalOptimum,
+    M: InputMap,
+{
+    /// Returns the optimum mapped by the inverse of the transform.
+    ///
+    /// Only the optimum inputs mapped within the input domain are reported, and `None` is returned if there is no such input
+    /// (`InputDistorted::new` always keeps the first one since it is the center of the transform).
+    fn global_optimum(&self) -> Option<Optimum> {
+        let optimum = self.inner.global_optimum()?;
+        let d = self.center.len();
+        let inputs = optimum
+            .inputs()
+            .iter()
+            .map(|zs| {
+                zs.iter()
+                    .zip(self.center.iter())
+                    .enumerate()
+                    .map(|(i, (z, c))| c + self.map.inverse(i, d, z - c))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|xs| {
+                xs.iter()
+                    .zip(self.inner.input_domain().iter())
+                    .all(|(&x, i)| i.contains(x))
+            })
+            .collect::<Vec<_>>();
+        if inputs.is_empty() {
+            None
+        } else {
+            Some(Optimum::new(optimum.value(), inputs))
+        }
+    }
+}
+
+/// Wrapper that applies a strictly increasing map to the outputs of a single objective.
+#[derive(Debug, Clone)]
+pub struct OutputDistorted<T, M> {
+    inner: T,
+    map: M,
+}
+impl<T, M> OutputDistorted<T, M>
+where
+    T: SingleObjective,
+    M: OutputMap,
+{
+    /// Makes a new `OutputDistorted` instance.
+    pub fn new(inner: T, map: M) -> Self {
+        Self { inner, map }
+    }
+
+    /// Returns a reference to the wrapped objective.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the wrapped objective.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+impl<T, M> Objective for OutputDistorted<T, M>
+where
+    T: SingleObjective,
+    M: OutputMap,
+{
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        self.inner.input_domain()
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        self.map.map(self.inner.evaluate(xs))
+    }
+
+    fn sense(&self) -> Sense {
+        self.inner.sense()
+    }
+
+    fn variable_types(&self) -> Vec<VariableType> {
+        self.inner.variable_types()
+    }
+
+    fn validate(&self, xs: &[f64]) -> Result<(), Error> {
+        self.inner.validate(xs)
+    }
+}
+impl<T, M> StochasticObjective for OutputDistorted<T, M>
+where
+    T: SingleObjective + StochasticObjective,
+    M: OutputMap,
+{
+    fn evaluate_with_seed(&self, xs: &[f64], seed: u64) -> f64 {
+        self.map.map(self.inner.evaluate_with_seed(xs, seed))
+    }
+}
+impl<T, M> Differentiable for OutputDistorted<T, M>
+where
+    T: Differentiable,
+    M: OutputMap,
+{
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        let (y, g) = self.inner.value_and_gradient(xs);
+        let k = self.map.derivative(y);
+        g.into_iter()
+            .map(|g| if g == 0.0 { 0.0 } else { k * g })
+            .collect()
+    }
+}
+impl<T, M> GlobalOptimum for OutputDistorted<T, M>
+where
+    T: SingleObjective + GlobalOptimum,
+    M: OutputMap,
+{
+    fn global_optimum(&self) -> Option<Optimum> {
+        let optimum = self.inner.global_optimum()?;
+        Some(Optimum::new(
+            self.map.map(optimum.value()),
+            optimum.inputs().to_vec(),
+        ))
+    }
+}
+
+/// Finds `x` such that `f(x) == y` for a strictly increasing function `f`.
+///
+/// # Panics
+///
+/// Panics if no bracket of `y` is found before the search interval overflows.
+fn bisect<F>(f: F, y: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let mut lo = -1.0;
+    let mut hi = 1.0;
+    while f(lo) > y {
+        lo *= 2.0;
+        assert!(lo.is_finite(), "{} is out of the range of the map", y);
+    }
+    while f(hi) < y {
+        hi *= 2.0;
+        assert!(hi.is_finite(), "{} is out of the range of the map", y);
+    }
+    for _ in 0..200 {
+        let mid = (lo + hi) / 2.0;
+        if mid == lo || mid == hi {
+            break;
+        }
+        if f(mid) < y {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mfb::ModifiedRastrigin;
+    use crate::test_util::{assert_gradient_with_tolerance, assert_sampled_global_optimum};
+    use crate::{Ackley, Adjiman, Sphere};
+    use std::num::NonZeroUsize;
+
+    #[test]
+    fn input_maps_work() {
+        assert_eq!(Oscillation::oscillate(0.0), 0.0);
+        assert_eq!(InputMap::map(&Asymmetry::new(0.5), 0, 3, 2.0), 2.0);
+        assert_eq!(InputMap::map(&Asymmetry::new(0.5), 1, 3, -2.0), -2.0);
+        assert_eq!(IllConditioning::new(100.0).map(2, 3, 1.0), 10.0);
+
+        let maps: [&dyn InputMap; 3] = [
+            &Oscillation,
+            &Asymmetry::new(0.2),
+            &IllConditioning::new(10.0),
+        ];
+        for m in &maps {
+            for &x in &[-3.0, -0.5, 0.0, 0.7, 4.0] {
+                let y = m.map(2, 4, x);
+                assert!((m.inverse(2, 4, y) - x).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn bisection_panics_out_of_range() {
+        struct Bounded;
+        impl InputMap for Bounded {
+            fn map(&self, _i: usize, _dimension: usize, x: f64) -> f64 {
+                x.atan()
+            }
+
+            fn derivative(&self, _i: usize, _dimension: usize, x: f64) -> f64 {
+                1.0 / (1.0 + x * x)
+            }
+        }
+        Bounded.inverse(0, 1, 2.0);
+    }
+
+    #[test]
+    fn input_distorted_works() {
+        let d = NonZeroUsize::new(3).unwrap();
+        let f = InputDistorted::new(
+            InputDistorted::new(Ackley::new(d), Asymmetry::new(0.2)),
+            Oscillation,
+        );
+        assert_sampled_global_optimum(&f, 1e-9);
+        assert_gradient_with_tolerance(&f, &[0.3, -1.1, 2.4], 1e-4);
+
+        let f = InputDistorted::new(ModifiedRastrigin::new(d), IllConditioning::new(10.0));
+        assert_sampled_global_optimum(&f, 1e-9);
+        assert_gradient_with_tolerance(&f, &[0.3, -0.1, 0.4], 1e-4);
+
+        let f = InputDistorted::new(Adjiman, Oscillation);
+        assert_eq!(
+            f.center(),
+            &Adjiman.global_optimum().unwrap().inputs()[0][..]
+        );
+        assert_sampled_global_optimum(&f, 1e-9);
+        assert_gradient_with_tolerance(&f, &[1.2, 0.3], 1e-4);
+
+        let f = InputDistorted::with_center(Adjiman, Asymmetry::new(0.5), vec![0.5, 0.0]);
+        assert_sampled_global_optimum(&f, 1e-9);
+        assert_gradient_with_tolerance(&f, &[1.2, 0.3], 1e-4);
+        assert_gradient_with_tolerance(&f, &[1.9, -0.9], 1e-4);
+    }
+
+    #[test]
+    fn output_distorted_works() {
+        let d = NonZeroUsize::new(2).unwrap();
+        let f = OutputDistorted::new(Ackley::new(d), Log);
+        assert_eq!(
+            f.evaluate(&[1.0, 2.0]),
+            Ackley::new(d).evaluate(&[1.0, 2.0]).ln_1p()
+        );
+        assert_sampled_global_optimum(&f, 1e-9);
+        assert_gradient_with_tolerance(&f, &[1.3, 2.2], 1e-4);
+
+        let f = OutputDistorted::new(Adjiman, Power::new(0.5));
+        assert_sampled_global_optimum(&f, 1e-9);
+        assert_gradient_with_tolerance(&f, &[1.3, 0.2], 1e-4);
+
+        let f = OutputDistorted::new(Sphere::new(d), Power::new(0.5));
+        assert_eq!(f.gradient(&[0.0, 0.0]), vec![0.0, 0.0]);
+        assert_gradient_with_tolerance(&f, &[1.3, 0.2], 1e-4);
+
+        let f = OutputDistorted::new(Adjiman, Oscillation);
+        assert_sampled_global_optimum(&f, 1e-9);
+        assert_gradient_with_tolerance(&f, &[1.3, 0.2], 1e-4);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{interval, GlobalOptimum, Interval, Objective, Optimum};
+use std::f64::consts::PI;
+
+/// Cross-in-Tray Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Cross-in-Tray Function](https://www.sfu.ca/~ssurjano/crossit.html)
+#[derive(Debug, Clone)]
+pub struct CrossInTray;
+impl Objective for CrossInTray {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-10.0, 10.0);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let r = (x.powi(2) + y.powi(2)).sqrt();
+        let e = (100.0 - r / PI).abs().exp();
+        -0.0001 * ((x.sin() * y.sin() * e).abs() + 1.0).powf(0.1)
+    }
+}
+impl GlobalOptimum for CrossInTray {
+    fn global_optimum(&self) -> Option<Optimum> {
+        const X: f64 = 1.3494066171539107;
+        Some(Optimum::new(
+            -2.062611870822737,
+            vec![vec![X, X], vec![X, -X], vec![-X, X], vec![-X, -X]],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_global_optimum, assert_reference_values};
+
+    #[test]
+    fn cross_in_tray_works() {
+        let xs = [
+            [0.16744750665503183, -1.7210791373653596],
+            [1.9782494437997897, -1.3791396541338585],
+            [-6.773587877567831, -3.8977680713452205],
+            [6.2518463726924125, -9.135230618555031],
+            [-9.073560019929747, 2.5270149107980693],
+        ];
+        let ys = [
+            -1.7407351003466855,
+            -2.018811247050894,
+            -1.5340944063589015,
+            -0.9662331028047436,
+            -1.3883136728238796,
+        ];
+
+        let f = CrossInTray;
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        assert_global_optimum(&CrossInTray);
+    }
+}
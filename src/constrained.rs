@@ -0,0 +1,498 @@
+//! Constrained single objective functions.
+//!
+//! # References
+//!
+//! - J. J. Liang et al., "Problem Definitions and Evaluation Criteria for the CEC 2006 Special Session on Constrained Real-Parameter Optimization", 2006.
+//! - J. B. Rosen and S. Suzuki, "Construction of Nonlinear Programming Test Problems", Communications of the ACM, 1965.
+//!
+//! The global optima reported by `GlobalOptimum` are the best known feasible solutions of the problems.
+use crate::{interval, GlobalOptimum, Interval, Objective, Optimum, SingleObjective};
+use std::f64::consts::PI;
+
+/// Single objective subject to constraints in addition to the input domain.
+///
+/// An input `xs` is feasible if `g(xs) <= 0` for every inequality constraint `g`
+/// and `h(xs) == 0` for every equality constraint `h`.
+pub trait ConstrainedObjective: SingleObjective {
+    /// Returns the values of the inequality constraints at `xs`.
+    fn inequality_constraints(&self, xs: &[f64]) -> Vec<f64>;
+
+    /// Returns the values of the equality constraints at `xs`.
+    ///
+    /// The default implementation returns an empty vector.
+    fn equality_constraints(&self, xs: &[f64]) -> Vec<f64> {
+        let _ = xs;
+        Vec::new()
+    }
+
+    /// Returns the sum of the constraint violations at `xs`.
+    fn constraint_violation(&self, xs: &[f64]) -> f64 {
+        let g = self
+            .inequality_constraints(xs)
+            .into_iter()
+            .map(|g| g.max(0.0))
+            .sum::<f64>();
+        let h = self
+            .equality_constraints(xs)
+            .into_iter()
+            .map(f64::abs)
+            .sum::<f64>();
+        g + h
+    }
+
+    /// Returns `true` if every constraint at `xs` is violated by at most `tolerance`.
+    fn is_feasible(&self, xs: &[f64], tolerance: f64) -> bool {
+        self.inequality_constraints(xs)
+            .into_iter()
+            .all(|g| g <= tolerance)
+            && self
+                .equality_constraints(xs)
+                .into_iter()
+                .all(|h| h.abs() <= tolerance)
+    }
+}
+
+/// Problem g01 of CEC 2006.
+#[derive(Debug, Clone)]
+pub struct G01;
+impl Objective for G01 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(0.0, 1.0);
+        const Y: Interval = interval(0.0, 100.0);
+        &[X, X, X, X, X, X, X, X, X, Y, Y, Y, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 13);
+
+        5.0 * xs[..4].iter().sum::<f64>()
+            - 5.0 * xs[..4].iter().map(|x| x.powi(2)).sum::<f64>()
+            - xs[4..].iter().sum::<f64>()
+    }
+}
+impl ConstrainedObjective for G01 {
+    fn inequality_constraints(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 13);
+
+        let x = |i: usize| xs[i - 1];
+        vec![
+            2.0 * x(1) + 2.0 * x(2) + x(10) + x(11) - 10.0,
+            2.0 * x(1) + 2.0 * x(3) + x(10) + x(12) - 10.0,
+            2.0 * x(2) + 2.0 * x(3) + x(11) + x(12) - 10.0,
+            -8.0 * x(1) + x(10),
+            -8.0 * x(2) + x(11),
+            -8.0 * x(3) + x(12),
+            -2.0 * x(4) - x(5) + x(10),
+            -2.0 * x(6) - x(7) + x(11),
+            -2.0 * x(8) - x(9) + x(12),
+        ]
+    }
+}
+impl GlobalOptimum for G01 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            -15.0,
+            vec![vec![
+                1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 3.0, 3.0, 3.0, 1.0,
+            ]],
+        ))
+    }
+}
+
+/// Problem g04 of CEC 2006.
+#[derive(Debug, Clone)]
+pub struct G04;
+impl Objective for G04 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X1: Interval = interval(78.0, 102.0);
+        const X2: Interval = interval(33.0, 45.0);
+        const X: Interval = interval(27.0, 45.0);
+        &[X1, X2, X, X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 5);
+
+        5.3578547 * xs[2].powi(2) + 0.8356891 * xs[0] * xs[4] + 37.293239 * xs[0] - 40792.141
+    }
+}
+impl ConstrainedObjective for G04 {
+    fn inequality_constraints(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 5);
+
+        let (x1, x2, x3, x4, x5) = (xs[0], xs[1], xs[2], xs[3], xs[4]);
+        let u = 85.334407 + 0.0056858 * x2 * x5 + 0.0006262 * x1 * x4 - 0.0022053 * x3 * x5;
+        let v = 80.51249 + 0.0071317 * x2 * x5 + 0.0029955 * x1 * x2 + 0.0021813 * x3.powi(2);
+        let w = 9.300961 + 0.0047026 * x3 * x5 + 0.0012547 * x1 * x3 + 0.0019085 * x3 * x4;
+        vec![u - 92.0, -u, v - 110.0, -v + 90.0, w - 25.0, -w + 20.0]
+    }
+}
+impl GlobalOptimum for G04 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            -30665.538671783317,
+            vec![vec![78.0, 33.0, 29.9952560256816, 45.0, 36.77581290578821]],
+        ))
+    }
+}
+
+/// Problem g06 of CEC 2006.
+#[derive(Debug, Clone)]
+pub struct G06;
+impl Objective for G06 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X1: Interval = interval(13.0, 100.0);
+        const X2: Interval = interval(0.0, 100.0);
+        &[X1, X2]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        (xs[0] - 10.0).powi(3) + (xs[1] - 20.0).powi(3)
+    }
+}
+impl ConstrainedObjective for G06 {
+    fn inequality_constraints(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x1, x2) = (xs[0], xs[1]);
+        vec![
+            -(x1 - 5.0).powi(2) - (x2 - 5.0).powi(2) + 100.0,
+            (x1 - 6.0).powi(2) + (x2 - 5.0).powi(2) - 82.81,
+        ]
+    }
+}
+impl GlobalOptimum for G06 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            -6961.813875580138,
+            vec![vec![14.095, 0.8429607892154796]],
+        ))
+    }
+}
+
+/// Problem g07 of CEC 2006.
+#[derive(Debug, Clone)]
+pub struct G07;
+impl Objective for G07 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-10.0, 10.0);
+        &[X; 10]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 10);
+
+        let x = |i: usize| xs[i - 1];
+        x(1).powi(2) + x(2).powi(2) + x(1) * x(2) - 14.0 * x(1) - 16.0 * x(2)
+            + (x(3) - 10.0).powi(2)
+            + 4.0 * (x(4) - 5.0).powi(2)
+            + (x(5) - 3.0).powi(2)
+            + 2.0 * (x(6) - 1.0).powi(2)
+            + 5.0 * x(7).powi(2)
+            + 7.0 * (x(8) - 11.0).powi(2)
+            + 2.0 * (x(9) - 10.0).powi(2)
+            + (x(10) - 7.0).powi(2)
+            + 45.0
+    }
+}
+impl ConstrainedObjective for G07 {
+    fn inequality_constraints(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 10);
+
+        let x = |i: usize| xs[i - 1];
+        vec![
+            -105.0 + 4.0 * x(1) + 5.0 * x(2) - 3.0 * x(7) + 9.0 * x(8),
+            10.0 * x(1) - 8.0 * x(2) - 17.0 * x(7) + 2.0 * x(8),
+            -8.0 * x(1) + 2.0 * x(2) + 5.0 * x(9) - 2.0 * x(10) - 12.0,
+            3.0 * (x(1) - 2.0).powi(2) + 4.0 * (x(2) - 3.0).powi(2) + 2.0 * x(3).powi(2)
+                - 7.0 * x(4)
+                - 120.0,
+            5.0 * x(1).powi(2) + 8.0 * x(2) + (x(3) - 6.0).powi(2) - 2.0 * x(4) - 40.0,
+            x(1).powi(2) + 2.0 * (x(2) - 2.0).powi(2) - 2.0 * x(1) * x(2) + 14.0 * x(5)
+                - 6.0 * x(6),
+            0.5 * (x(1) - 8.0).powi(2) + 2.0 * (x(2) - 4.0).powi(2) + 3.0 * x(5).powi(2)
+                - x(6)
+                - 30.0,
+            -3.0 * x(1) + 6.0 * x(2) + 12.0 * (x(9) - 8.0).powi(2) - 7.0 * x(10),
+        ]
+    }
+}
+impl GlobalOptimum for G07 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            24.30620906817991,
+            vec![vec![
+                2.17199634142692,
+                2.3636830416034,
+                8.77392573913157,
+                5.09598443745173,
+                0.990654756560493,
+                1.43057392853463,
+                1.32164415364306,
+                9.82872576524495,
+                8.2800915887356,
+                8.3759266477347,
+            ]],
+        ))
+    }
+}
+
+/// Problem g08 of CEC 2006.
+#[derive(Debug, Clone)]
+pub struct G08;
+impl Objective for G08 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(0.0, 10.0);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x1, x2) = (xs[0], xs[1]);
+        -(2.0 * PI * x1).sin().powi(3) * (2.0 * PI * x2).sin() / (x1.powi(3) * (x1 + x2))
+    }
+}
+impl ConstrainedObjective for G08 {
+    fn inequality_constraints(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x1, x2) = (xs[0], xs[1]);
+        vec![x1.powi(2) - x2 + 1.0, 1.0 - x1 + (x2 - 4.0).powi(2)]
+    }
+}
+impl GlobalOptimum for G08 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            -0.09582504141803586,
+            vec![vec![1.227971352607526, 4.245373366122749]],
+        ))
+    }
+}
+
+/// Problem g09 of CEC 2006.
+#[derive(Debug, Clone)]
+pub struct G09;
+impl Objective for G09 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-10.0, 10.0);
+        &[X; 7]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 7);
+
+        let x = |i: usize| xs[i - 1];
+        (x(1) - 10.0).powi(2)
+            + 5.0 * (x(2) - 12.0).powi(2)
+            + x(3).powi(4)
+            + 3.0 * (x(4) - 11.0).powi(2)
+            + 10.0 * x(5).powi(6)
+            + 7.0 * x(6).powi(2)
+            + x(7).powi(4)
+            - 4.0 * x(6) * x(7)
+            - 10.0 * x(6)
+            - 8.0 * x(7)
+    }
+}
+impl ConstrainedObjective for G09 {
+    fn inequality_constraints(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 7);
+
+        let x = |i: usize| xs[i - 1];
+        vec![
+            -127.0
+                + 2.0 * x(1).powi(2)
+                + 3.0 * x(2).powi(4)
+                + x(3)
+                + 4.0 * x(4).powi(2)
+                + 5.0 * x(5),
+            -282.0 + 7.0 * x(1) + 3.0 * x(2) + 10.0 * x(3).powi(2) + x(4) - x(5),
+            -196.0 + 23.0 * x(1) + x(2).powi(2) + 6.0 * x(6).powi(2) - 8.0 * x(7),
+            4.0 * x(1).powi(2) + x(2).powi(2) - 3.0 * x(1) * x(2) + 2.0 * x(3).powi(2) + 5.0 * x(6)
+                - 11.0 * x(7),
+        ]
+    }
+}
+impl GlobalOptimum for G09 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            680.6300573744021,
+            vec![vec![
+                2.330499351474052,
+                1.951372368471146,
+                -0.4775413995106158,
+                4.365726249236259,
+                -0.624486959100389,
+                1.0381309941096217,
+                1.594226678067152,
+            ]],
+        ))
+    }
+}
+
+/// Problem g11 of CEC 2006.
+#[derive(Debug, Clone)]
+pub struct G11;
+impl Objective for G11 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-1.0, 1.0);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        xs[0].powi(2) + (xs[1] - 1.0).powi(2)
+    }
+}
+impl ConstrainedObjective for G11 {
+    fn inequality_constraints(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        Vec::new()
+    }
+
+    fn equality_constraints(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        vec![xs[1] - xs[0].powi(2)]
+    }
+}
+impl GlobalOptimum for G11 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        let x = 0.5f64.sqrt();
+        Some(Optimum::new(0.75, vec![vec![-x, 0.5], vec![x, 0.5]]))
+    }
+}
+
+/// Problem g24 of CEC 2006.
+#[derive(Debug, Clone)]
+pub struct G24;
+impl Objective for G24 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X1: Interval = interval(0.0, 3.0);
+        const X2: Interval = interval(0.0, 4.0);
+        &[X1, X2]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        -xs[0] - xs[1]
+    }
+}
+impl ConstrainedObjective for G24 {
+    fn inequality_constraints(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x1, x2) = (xs[0], xs[1]);
+        vec![
+            -2.0 * x1.powi(4) + 8.0 * x1.powi(3) - 8.0 * x1.powi(2) + x2 - 2.0,
+            -4.0 * x1.powi(4) + 32.0 * x1.powi(3) - 88.0 * x1.powi(2) + 96.0 * x1 + x2 - 36.0,
+        ]
+    }
+}
+impl GlobalOptimum for G24 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            -5.50801327159536,
+            vec![vec![2.32952019747762, 3.17849307411774]],
+        ))
+    }
+}
+
+/// Rosen-Suzuki problem.
+///
+/// The original problem is unbounded except for the constraints, and `[-10, 10]^4` is used as the input domain.
+#[derive(Debug, Clone)]
+pub struct RosenSuzuki;
+impl Objective for RosenSuzuki {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-10.0, 10.0);
+        &[X; 4]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 4);
+
+        let (x1, x2, x3, x4) = (xs[0], xs[1], xs[2], xs[3]);
+        x1.powi(2) + x2.powi(2) + 2.0 * x3.powi(2) + x4.powi(2) - 5.0 * x1 - 5.0 * x2 - 21.0 * x3
+            + 7.0 * x4
+    }
+}
+impl ConstrainedObjective for RosenSuzuki {
+    fn inequality_constraints(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 4);
+
+        let (x1, x2, x3, x4) = (xs[0], xs[1], xs[2], xs[3]);
+        vec![
+            x1.powi(2) + x2.powi(2) + x3.powi(2) + x4.powi(2) + x1 - x2 + x3 - x4 - 8.0,
+            x1.powi(2) + 2.0 * x2.powi(2) + x3.powi(2) + 2.0 * x4.powi(2) - x1 - x4 - 10.0,
+            2.0 * x1.powi(2) + x2.powi(2) + x3.powi(2) + 2.0 * x1 - x2 - x4 - 5.0,
+        ]
+    }
+}
+impl GlobalOptimum for RosenSuzuki {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(-44.0, vec![vec![0.0, 1.0, 2.0, -1.0]]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_constrained_optimum;
+
+    #[test]
+    fn constrained_optima_work() {
+        assert_constrained_optimum(&G01, 1e-9);
+        assert_constrained_optimum(&G04, 1e-9);
+        assert_constrained_optimum(&G06, 1e-9);
+        assert_constrained_optimum(&G07, 1e-9);
+        assert_constrained_optimum(&G08, 1e-9);
+        assert_constrained_optimum(&G09, 1e-9);
+        assert_constrained_optimum(&G11, 1e-9);
+        assert_constrained_optimum(&G24, 1e-9);
+        assert_constrained_optimum(&RosenSuzuki, 1e-9);
+    }
+
+    #[test]
+    fn constraint_violation_works() {
+        assert_eq!(
+            RosenSuzuki.constraint_violation(&[0.0, 1.0, 2.0, -1.0]),
+            0.0
+        );
+        assert_eq!(
+            RosenSuzuki.inequality_constraints(&[0.0, 0.0, 0.0, 0.0]),
+            vec![-8.0, -10.0, -5.0]
+        );
+        assert_eq!(
+            RosenSuzuki.constraint_violation(&[3.0, 0.0, 0.0, 0.0]),
+            23.0
+        );
+        assert_eq!(G11.constraint_violation(&[0.5, 0.5]), 0.25);
+        assert!(!G11.is_feasible(&[0.5, 0.5], 1e-4));
+        assert!(G01.equality_constraints(&[0.0; 13]).is_empty());
+    }
+}
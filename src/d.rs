@@ -0,0 +1,191 @@
+use crate::{interval, Differentiable, GlobalOptimum, Interval, Objective, Optimum};
+use std::num::NonZeroUsize;
+
+/// Dixon-Price Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Dixon-Price Function](https://www.sfu.ca/~ssurjano/dixonpr.html)
+#[derive(Debug, Clone)]
+pub struct DixonPrice {
+    input_domain: Vec<Interval>,
+}
+impl DixonPrice {
+    /// Makes a new `DixonPrice` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get())
+            .map(|_| interval(-10.0, 10.0))
+            .collect();
+        Self { input_domain }
+    }
+}
+impl Objective for DixonPrice {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        (xs[0] - 1.0).powi(2)
+            + xs.windows(2)
+                .enumerate()
+                .map(|(i, w)| (i + 2) as f64 * (2.0 * w[1] * w[1] - w[0]).powi(2))
+                .sum::<f64>()
+    }
+}
+impl GlobalOptimum for DixonPrice {
+    /// Returns the optimum at `x_i = 2^(-(2^i - 2) / 2^i)`.
+    ///
+    /// Since only the square of the last element appears in the function, its sign is arbitrary.
+    fn global_optimum(&self) -> Option<Optimum> {
+        let xs = (1..=self.dimension().get())
+            .map(|i| {
+                let p = 2f64.powi(i as i32);
+                2f64.powf(-(p - 2.0) / p)
+            })
+            .collect::<Vec<_>>();
+        let mut inputs = vec![xs.clone()];
+        if xs.len() > 1 {
+            let mut ys = xs;
+            let last = ys.len() - 1;
+            ys[last] = -ys[last];
+            inputs.push(ys);
+        }
+        Some(Optimum::new(0.0, inputs))
+    }
+}
+impl Differentiable for DixonPrice {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let n = xs.len();
+        (0..n)
+            .map(|k| {
+                let mut g = if k == 0 {
+                    2.0 * (xs[0] - 1.0)
+                } else {
+                    let t = 2.0 * xs[k] * xs[k] - xs[k - 1];
+                    2.0 * (k + 1) as f64 * t * 4.0 * xs[k]
+                };
+                if k + 1 < n {
+                    let t = 2.0 * xs[k + 1] * xs[k + 1] - xs[k];
+                    g -= 2.0 * (k + 2) as f64 * t;
+                }
+                g
+            })
+            .collect()
+    }
+}
+
+/// Drop-Wave Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Drop-Wave Function](https://www.sfu.ca/~ssurjano/drop.html)
+#[derive(Debug, Clone)]
+pub struct DropWave;
+impl Objective for DropWave {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-5.12, 5.12);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let r2 = x.powi(2) + y.powi(2);
+        -(1.0 + (12.0 * r2.sqrt()).cos()) / (0.5 * r2 + 2.0)
+    }
+}
+impl GlobalOptimum for DropWave {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(-1.0, vec![vec![0.0, 0.0]]))
+    }
+}
+impl Differentiable for DropWave {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let r2 = x.powi(2) + y.powi(2);
+        let r = r2.sqrt();
+        if r == 0.0 {
+            return vec![0.0, 0.0];
+        }
+        let d = 0.5 * r2 + 2.0;
+        let k = (12.0 * (12.0 * r).sin() / d + (1.0 + (12.0 * r).cos()) * r / d.powi(2)) / r;
+        vec![k * x, k * y]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_global_optimum, assert_gradient, assert_reference_values};
+
+    #[test]
+    fn dixon_price_works() {
+        let xs = [
+            [4.276340403483985, -5.777500326488035, 6.632158605467083],
+            [1.470647047025695, -4.300850760275896, -8.730788457095413],
+            [7.078849768453605, 9.796120298431624, -8.229638137805432],
+            [6.011906425150038, -1.7907634530818228, -6.984692510943809],
+            [-4.122175063618756, 5.375837745546892, 7.4553404925640265],
+        ];
+        let ys = [
+            34185.28009582889,
+            76239.75235607619,
+            115744.95259295792,
+            29644.23421931389,
+            41268.300771210925,
+        ];
+
+        let f = DixonPrice::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        assert_global_optimum(&DixonPrice::new(NonZeroUsize::new(1).unwrap()));
+        assert_global_optimum(&DixonPrice::new(NonZeroUsize::new(5).unwrap()));
+        assert_global_optimum(&DropWave);
+    }
+
+    #[test]
+    fn gradients_work() {
+        assert_gradient(
+            &DixonPrice::new(NonZeroUsize::new(3).unwrap()),
+            &[0.3, -1.2, 2.5],
+        );
+        for xs in &[[0.3, -1.2], [1.7, 0.4], [-0.5, 0.9]] {
+            assert_gradient(&DropWave, xs);
+        }
+    }
+
+    #[test]
+    fn drop_wave_works() {
+        let xs = [
+            [-2.2483639130499204, 0.35452717956528357],
+            [-0.2945015181824715, -1.6092849620771221],
+            [5.09213567152243, -3.1173274180268504],
+            [-0.8929827183889918, -3.0446528903946617],
+            [1.3584894135400347, -2.2906384900128445],
+        ];
+        let ys = [
+            -0.0930155855462904,
+            -0.5119745761257528,
+            -0.009095498166389196,
+            -0.27442751200229304,
+            -0.3347426243434212,
+        ];
+
+        let f = DropWave;
+        assert_reference_values(&f, &xs, &ys);
+    }
+}
@@ -0,0 +1,332 @@
+use crate::mfso::{self, MultiFidelitySingleObjective};
+use crate::{Error, GlobalOptimum, Interval, Objective, Optimum, Sense, StochasticObjective};
+
+/// Type of an input variable.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VariableType {
+    /// Real-valued variable.
+    Continuous,
+
+    /// Integer-valued variable.
+    Integer,
+
+    /// Variable taking one of the given values (sorted in ascending order).
+    Levels(Vec<f64>),
+}
+impl VariableType {
+    /// Returns `true` if `x` is a feasible value of this type.
+    pub fn contains(&self, x: f64) -> bool {
+        match self {
+            VariableType::Continuous => true,
+            VariableType::Integer => x.fract() == 0.0,
+            VariableType::Levels(levels) => levels.contains(&x),
+        }
+    }
+
+    /// Returns the feasible value within `domain` nearest to `x`.
+    ///
+    /// Ties are broken toward the smaller value.
+    pub fn round(&self, x: f64, domain: Interval) -> f64 {
+        match self {
+            VariableType::Continuous => x,
+            VariableType::Integer => (x - 0.5)
+                .ceil()
+                .clamp(domain.min().ceil(), domain.max().floor()),
+            VariableType::Levels(levels) => {
+                levels.iter().copied().fold(levels[0], |nearest, level| {
+                    if (level - x).abs() < (nearest - x).abs() {
+                        level
+                    } else {
+                        nearest
+                    }
+                })
+            }
+        }
+    }
+
+    /// Returns the type of the variable `x` such that `inverse^-1(x)` is a variable of this type within `inner_domain`.
+    ///
+    /// `inverse` must be strictly increasing, and the feasible values are restricted to `domain`.
+    pub(crate) fn pull_back<F>(&self, inner_domain: Interval, domain: Interval, inverse: F) -> Self
+    where
+        F: Fn(f64) -> f64,
+    {
+        let values = match self {
+            VariableType::Continuous => return VariableType::Continuous,
+            VariableType::Integer => {
+                let (min, max) = (inner_domain.min().ceil(), inner_domain.max().floor());
+                (0..)
+                    .map(|i| min + f64::from(i))
+                    .take_while(|&x| x <= max)
+                    .collect::<Vec<_>>()
+            }
+            VariableType::Levels(levels) => levels
+                .iter()
+                .copied()
+                .filter(|&x| inner_domain.contains(x))
+                .collect(),
+        };
+        VariableType::Levels(
+            values
+                .into_iter()
+                .map(inverse)
+                .filter(|&x| domain.contains(x))
+                .collect(),
+        )
+    }
+}
+
+/// How to handle inputs that are not feasible values of their variable types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffGridPolicy {
+    /// Rounds off-grid inputs to the nearest feasible values before evaluation.
+    Round,
+
+    /// Rejects off-grid inputs (`validate` returns `Error::OffGrid` and `evaluate` panics).
+    Reject,
+}
+
+/// Wrapper that restricts some dimensions of an objective to integers or finite sets of levels.
+#[derive(Debug, Clone)]
+pub struct Discretized<T> {
+    inner: T,
+    variable_types: Vec<VariableType>,
+    policy: OffGridPolicy,
+}
+impl<T> Discretized<T>
+where
+    T: Objective,
+{
+    /// Makes a new `Discretized` instance whose variables are all continuous.
+    ///
+    /// The default policy is `OffGridPolicy::Round`.
+    pub fn new(inner: T) -> Self {
+        let variable_types = inner.variable_types();
+        Self {
+            inner,
+            variable_types,
+            policy: OffGridPolicy::Round,
+        }
+    }
+
+    /// Sets the type of the `index`-th variable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of the dimension,
+    /// or if the input domain of the variable contains no feasible value of `variable_type`.
+    pub fn with_variable_type(mut self, index: usize, variable_type: VariableType) -> Self {
+        let domain = self.inner.input_domain()[index];
+        let variable_type = match variable_type {
+            VariableType::Continuous => VariableType::Continuous,
+            VariableType::Integer => {
+                assert!(domain.min().ceil() <= domain.max().floor());
+                VariableType::Integer
+            }
+            VariableType::Levels(mut levels) => {
+                assert!(!levels.is_empty());
+                assert!(levels.iter().all(|&x| domain.contains(x)));
+                levels.sort_by(|a, b| a.partial_cmp(b).unwrap_or_else(|| panic!()));
+                levels.dedup();
+                VariableType::Levels(levels)
+            }
+        };
+        self.variable_types[index] = variable_type;
+        self
+    }
+
+    /// Sets the policy for off-grid inputs.
+    pub fn with_policy(mut self, policy: OffGridPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns the policy for off-grid inputs.
+    pub fn policy(&self) -> OffGridPolicy {
+        self.policy
+    }
+
+    /// Returns a reference to the wrapped objective.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the wrapped objective.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Rounds `xs` to the nearest feasible point.
+    pub fn round(&self, xs: &[f64]) -> Vec<f64> {
+        xs.iter()
+            .zip(self.variable_types.iter())
+            .zip(self.inner.input_domain().iter())
+            .map(|((&x, t), &domain)| t.round(x, domain))
+            .collect()
+    }
+
+    fn off_grid(&self, xs: &[f64]) -> Option<(usize, f64)> {
+        xs.iter()
+            .zip(self.variable_types.iter())
+            .enumerate()
+            .find(|(_, (&x, t))| !t.contains(x))
+            .map(|(index, (&x, _))| (index, x))
+    }
+
+    fn prepare(&self, xs: &[f64]) -> Vec<f64> {
+        match self.policy {
+            OffGridPolicy::Round => self.round(xs),
+            OffGridPolicy::Reject => {
+                assert_eq!(self.off_grid(xs), None);
+                xs.to_vec()
+            }
+        }
+    }
+}
+impl<T> Objective for Discretized<T>
+where
+    T: Objective,
+{
+    type Output = T::Output;
+
+    fn input_domain(&self) -> &[Interval] {
+        self.inner.input_domain()
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> Self::Output {
+        self.inner.evaluate(&self.prepare(xs))
+    }
+
+    fn sense(&self) -> Sense {
+        self.inner.sense()
+    }
+
+    fn variable_types(&self) -> Vec<VariableType> {
+        self.variable_types.clone()
+    }
+
+    /// Checks whether `xs` is a valid input for this objective.
+    ///
+    /// In addition to the checks of the inner objective,
+    /// off-grid inputs are reported as errors if the policy is `OffGridPolicy::Reject`.
+    fn validate(&self, xs: &[f64]) -> Result<(), Error> {
+        self.inner.validate(xs)?;
+        if self.policy == OffGridPolicy::Reject {
+            if let Some((index, value)) = self.off_grid(xs) {
+                return Err(Error::OffGrid { index, value });
+            }
+        }
+        Ok(())
+    }
+}
+impl<T> MultiFidelitySingleObjective for Discretized<T>
+where
+    T: MultiFidelitySingleObjective,
+{
+    fn max_cost(&self) -> mfso::Cost {
+        self.inner.max_cost()
+    }
+}
+impl<T> StochasticObjective for Discretized<T>
+where
+    T: StochasticObjective,
+{
+    fn evaluate_with_seed(&self, xs: &[f64], seed: u64) -> Self::Output {
+        self.inner.evaluate_with_seed(&self.prepare(xs), seed)
+    }
+}
+impl<T> GlobalOptimum for Discretized<T>
+where
+    T: Objective + GlobalOptimum,
+{
+    /// Returns the optimum of the inner objective if it is attained on the grid.
+    ///
+    /// Optimum inputs that are off the grid are excluded, and `None` is returned if no input remains
+    /// (the optimum of the discretized objective is unknown in that case).
+    fn global_optimum(&self) -> Option<Optimum> {
+        let optimum = self.inner.global_optimum()?;
+        let inputs = optimum
+            .inputs()
+            .iter()
+            .filter(|xs| self.off_grid(xs).is_none())
+            .cloned()
+            .collect::<Vec<_>>();
+        if inputs.is_empty() {
+            None
+        } else {
+            Some(Optimum::new(optimum.value(), inputs))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mfso::Borehole;
+    use crate::{Ackley, SingleObjective};
+    use std::num::NonZeroUsize;
+
+    #[test]
+    fn discretized_works() {
+        let ackley = Ackley::new(NonZeroUsize::new(3).unwrap());
+        let f = Discretized::new(ackley.clone())
+            .with_variable_type(0, VariableType::Integer)
+            .with_variable_type(2, VariableType::Levels(vec![0.5, -1.0, 0.0]));
+        assert_eq!(
+            f.variable_types(),
+            vec![
+                VariableType::Integer,
+                VariableType::Continuous,
+                VariableType::Levels(vec![-1.0, 0.0, 0.5])
+            ]
+        );
+        assert_eq!(f.round(&[1.4, 0.3, 0.3]), vec![1.0, 0.3, 0.5]);
+        assert_eq!(f.round(&[40.0, 0.3, -9.0]), vec![32.0, 0.3, -1.0]);
+        assert_eq!(f.round(&[1.5, 0.3, 0.25]), vec![1.0, 0.3, 0.0]);
+        assert_eq!(f.round(&[-2.5, 0.3, -0.5]), vec![-3.0, 0.3, -1.0]);
+        assert_eq!(
+            f.evaluate(&[1.4, 0.3, 0.3]),
+            ackley.evaluate(&[1.0, 0.3, 0.5])
+        );
+        assert!(f.validate(&[1.4, 0.3, 0.3]).is_ok());
+        assert_eq!(
+            f.global_optimum().unwrap().inputs(),
+            &[vec![0.0, 0.0, 0.0]][..]
+        );
+
+        let f = f.with_policy(OffGridPolicy::Reject);
+        assert!(f.try_evaluate(&[1.0, 0.3, 0.5]).is_ok());
+        assert_eq!(
+            f.try_evaluate(&[1.0, 0.3, 0.3]),
+            Err(Error::OffGrid {
+                index: 2,
+                value: 0.3
+            })
+        );
+        assert!(matches!(
+            f.try_evaluate(&[40.0, 0.3, 0.3]),
+            Err(Error::OutOfDomain { index: 0, .. })
+        ));
+
+        let f = Discretized::new(ackley).with_variable_type(0, VariableType::Levels(vec![1.0]));
+        assert_eq!(f.global_optimum(), None);
+    }
+
+    #[test]
+    fn discretized_multi_fidelity_works() {
+        let f = Discretized::new(Borehole::default())
+            .with_variable_type(1, VariableType::Integer)
+            .with_variable_type(5, VariableType::Levels(vec![700.0, 760.0, 820.0]));
+        let xs = [
+            0.1, 1000.4, 80_000.0, 1_000.0, 100.0, 750.0, 1_500.0, 10_000.0,
+        ];
+        let rounded = [
+            0.1, 1000.0, 80_000.0, 1_000.0, 100.0, 760.0, 1_500.0, 10_000.0,
+        ];
+        let actual = f.evaluate(&xs).collect::<Vec<_>>();
+        let expected = Borehole::default().evaluate(&rounded).collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+        assert_eq!(f.max_cost(), Borehole::default().max_cost());
+        assert!(f.global_optimum().is_some());
+    }
+}
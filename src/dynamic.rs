@@ -0,0 +1,468 @@
+//! Dynamic (time-varying) objectives.
+//!
+//! # References
+//!
+//! - J. Branke, "Memory Enhanced Evolutionary Algorithms for Changing Optimization Problems", CEC 1999.
+use crate::mfso::{self, MultiFidelitySingleObjective};
+use crate::transform::{self, Penalize, Shifted};
+use crate::{
+    Differentiable, Error, GlobalOptimum, Interval, Objective, Optimum, Sense, StochasticObjective,
+    VariableType,
+};
+use rand::distributions::{Distribution, Normal};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::num::NonZeroUsize;
+
+/// Objective whose landscape changes over discrete time steps.
+///
+/// `GlobalOptimum` (if implemented) reports the optimum at the current time step.
+pub trait DynamicObjective: Objective {
+    /// Returns the number of changes applied so far.
+    fn time(&self) -> u64;
+
+    /// Changes the landscape to the next time step.
+    fn advance(&mut self);
+}
+
+/// Moving Peaks Benchmark with cone peaks.
+///
+/// This is a maximization problem.
+/// The default parameters follow the scenario 2 of the benchmark,
+/// except that the dimension and the number of peaks are configurable.
+/// All the random numbers (the initial peaks and the changes) are drawn from the RNG seeded by `seed`,
+/// so the sequence of landscapes is reproducible.
+#[derive(Debug, Clone)]
+pub struct MovingPeaks {
+    domain: Vec<Interval>,
+    peaks: Vec<Peak>,
+    change_severity: f64,
+    correlation: f64,
+    rng: StdRng,
+    time: u64,
+}
+impl MovingPeaks {
+    const DOMAIN: Interval = unsafe { Interval::new_unchecked(0.0, 100.0) };
+    const HEIGHT: Interval = unsafe { Interval::new_unchecked(30.0, 70.0) };
+    const WIDTH: Interval = unsafe { Interval::new_unchecked(1.0, 12.0) };
+    const HEIGHT_SEVERITY: f64 = 7.0;
+    const WIDTH_SEVERITY: f64 = 1.0;
+
+    /// Makes a new `MovingPeaks` instance.
+    ///
+    /// The peaks are placed uniformly at random, and their heights and widths are sampled uniformly from their ranges.
+    pub fn new(dimension: NonZeroUsize, peak_count: NonZeroUsize, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let domain = vec![Self::DOMAIN; dimension.get()];
+        let peaks = (0..peak_count.get())
+            .map(|_| Peak {
+                position: domain
+                    .iter()
+                    .map(|i| transform::sample(i, &mut rng))
+                    .collect(),
+                height: transform::sample(&Self::HEIGHT, &mut rng),
+                width: transform::sample(&Self::WIDTH, &mut rng),
+                velocity: vec![0.0; dimension.get()],
+            })
+            .collect();
+        Self {
+            domain,
+            peaks,
+            change_severity: 1.0,
+            correlation: 0.0,
+            rng,
+            time: 0,
+        }
+    }
+
+    /// Makes a new `MovingPeaks` instance with the scenario 2 settings (5 dimensions and 10 peaks).
+    pub fn scenario2(seed: u64) -> Self {
+        Self::new(
+            NonZeroUsize::new(5).unwrap_or_else(|| panic!()),
+            NonZeroUsize::new(10).unwrap_or_else(|| panic!()),
+            seed,
+        )
+    }
+
+    /// Sets the distance each peak moves per time step (the default is `1.0`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `change_severity` is negative.
+    pub fn with_change_severity(mut self, change_severity: f64) -> Self {
+        assert!(change_severity >= 0.0);
+        self.change_severity = change_severity;
+        self
+    }
+
+    /// Sets the correlation between consecutive movements of each peak (the default is `0.0`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `correlation` is not within `[0, 1]`.
+    pub fn with_correlation(mut self, correlation: f64) -> Self {
+        assert!((0.0..=1.0).contains(&correlation));
+        self.correlation = correlation;
+        self
+    }
+
+    /// Returns the current positions of the peaks.
+    pub fn peak_positions(&self) -> impl '_ + Iterator<Item = &[f64]> {
+        self.peaks.iter().map(|p| &p.position[..])
+    }
+}
+impl Objective for MovingPeaks {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.domain.len());
+
+        self.peaks
+            .iter()
+            .map(|p| p.height - p.width * distance(xs, &p.position))
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    fn sense(&self) -> Sense {
+        Sense::Maximize
+    }
+}
+impl DynamicObjective for MovingPeaks {
+    fn time(&self) -> u64 {
+        self.time
+    }
+
+    fn advance(&mut self) {
+        for peak in &mut self.peaks {
+            peak.advance(
+                &self.domain,
+                self.change_severity,
+                self.correlation,
+                &mut self.rng,
+            );
+        }
+        self.time += 1;
+    }
+}
+impl GlobalOptimum for MovingPeaks {
+    /// Returns the highest peak(s).
+    fn global_optimum(&self) -> Option<Optimum> {
+        let height = self
+            .peaks
+            .iter()
+            .map(|p| p.height)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let inputs = self
+            .peaks
+            .iter()
+            .filter(|p| p.height == height)
+            .map(|p| p.position.clone())
+            .collect();
+        Some(Optimum::new(height, inputs))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Peak {
+    position: Vec<f64>,
+    height: f64,
+    width: f64,
+    velocity: Vec<f64>,
+}
+impl Peak {
+    fn advance<R>(&mut self, domain: &[Interval], severity: f64, correlation: f64, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        let normal = Normal::new(0.0, 1.0);
+        let random = normalize(
+            self.velocity.iter().map(|_| normal.sample(rng)).collect(),
+            severity,
+        );
+        let velocity = random
+            .iter()
+            .zip(self.velocity.iter())
+            .map(|(r, v)| (1.0 - correlation) * r + correlation * v)
+            .collect();
+        self.velocity = normalize(velocity, severity);
+        for ((x, v), domain) in self
+            .position
+            .iter_mut()
+            .zip(self.velocity.iter_mut())
+            .zip(domain.iter())
+        {
+            let moved = *x + *v;
+            *x = reflect(moved, domain);
+            if *x != moved {
+                *v = -*v;
+            }
+        }
+        self.height = reflect(
+            self.height + MovingPeaks::HEIGHT_SEVERITY * normal.sample(rng),
+            &MovingPeaks::HEIGHT,
+        );
+        self.width = reflect(
+            self.width + MovingPeaks::WIDTH_SEVERITY * normal.sample(rng),
+            &MovingPeaks::WIDTH,
+        );
+    }
+}
+
+/// Wrapper that moves the global optimum of an objective by a random step at each time step.
+///
+/// This is a `Shifted` objective whose shift performs a random walk:
+/// at each time step, the optimum moves by `severity` in a direction sampled uniformly by using the seeded RNG.
+/// All the optimum inputs are kept within the input domain by reflecting the shift at the boundaries of
+/// the range in which every optimum input stays within the domain.
+#[derive(Debug, Clone)]
+pub struct Drifting<T> {
+    inner: Shifted<T>,
+    shift_range: Vec<Interval>,
+    severity: f64,
+    rng: StdRng,
+    time: u64,
+}
+impl<T> Drifting<T>
+where
+    T: Objective + GlobalOptimum,
+{
+    /// Makes a new `Drifting` instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the global optimum of `inner` is unknown, some of the optimum inputs are out of the input domain,
+    /// or `severity` is negative.
+    pub fn new(inner: T, severity: f64, seed: u64) -> Self {
+        assert!(severity >= 0.0);
+        let optimum = inner.global_optimum().unwrap_or_else(|| panic!());
+        let shift_range = inner
+            .input_domain()
+            .iter()
+            .enumerate()
+            .map(|(i, domain)| {
+                let (min, max) = optimum
+                    .inputs()
+                    .iter()
+                    .fold((domain.max(), domain.min()), |(min, max), xs| {
+                        (min.min(xs[i]), max.max(xs[i]))
+                    });
+                Interval::new(domain.min() - min, domain.max() - max).unwrap_or_else(|_| panic!())
+            })
+            .collect::<Vec<_>>();
+        let shift = vec![0.0; shift_range.len()];
+        Self {
+            inner: Shifted::new(inner, shift),
+            shift_range,
+            severity,
+            rng: StdRng::seed_from_u64(seed),
+            time: 0,
+        }
+    }
+
+    /// Returns the current shift vector.
+    pub fn shift(&self) -> &[f64] {
+        self.inner.shift()
+    }
+
+    /// Returns a reference to the wrapped objective.
+    pub fn inner(&self) -> &T {
+        self.inner.inner()
+    }
+
+    /// Returns the wrapped objective.
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner()
+    }
+}
+impl<T> Objective for Drifting<T>
+where
+    T: Objective,
+    T::Output: Penalize,
+{
+    type Output = T::Output;
+
+    fn input_domain(&self) -> &[Interval] {
+        self.inner.input_domain()
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> Self::Output {
+        self.inner.evaluate(xs)
+    }
+
+    fn sense(&self) -> Sense {
+        self.inner.sense()
+    }
+    fn variable_types(&self) -> Vec<VariableType> {
+        self.inner.variable_types()
+    }
+
+    fn validate(&self, xs: &[f64]) -> Result<(), Error> {
+        self.inner.validate(xs)
+    }
+}
+impl<T> DynamicObjective for Drifting<T>
+where
+    T: Objective,
+    T::Output: Penalize,
+{
+    fn time(&self) -> u64 {
+        self.time
+    }
+
+    fn advance(&mut self) {
+        let normal = Normal::new(0.0, 1.0);
+        let step = normalize(
+            (0..self.shift_range.len())
+                .map(|_| normal.sample(&mut self.rng))
+                .collect(),
+            self.severity,
+        );
+        for ((s, d), range) in self
+            .inner
+            .shift_mut()
+            .iter_mut()
+            .zip(step)
+            .zip(self.shift_range.iter())
+        {
+            *s = reflect(*s + d, range);
+        }
+        self.time += 1;
+    }
+}
+impl<T> MultiFidelitySingleObjective for Drifting<T>
+where
+    T: MultiFidelitySingleObjective,
+{
+    fn max_cost(&self) -> mfso::Cost {
+        self.inner.max_cost()
+    }
+}
+impl<T> StochasticObjective for Drifting<T>
+where
+    T: StochasticObjective,
+    T::Output: Penalize,
+{
+    fn evaluate_with_seed(&self, xs: &[f64], seed: u64) -> Self::Output {
+        self.inner.evaluate_with_seed(xs, seed)
+    }
+}
+impl<T> Differentiable for Drifting<T>
+where
+    T: Differentiable,
+{
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        self.inner.gradient(xs)
+    }
+}
+impl<T> GlobalOptimum for Drifting<T>
+where
+    T: Objective + GlobalOptimum,
+{
+    /// Returns the optimum at the current time step.
+    fn global_optimum(&self) -> Option<Optimum> {
+        self.inner.global_optimum()
+    }
+}
+
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| (a - b).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Scales `xs` to have the given length (zero vectors are left as is).
+fn normalize(xs: Vec<f64>, length: f64) -> Vec<f64> {
+    let norm = xs.iter().map(|x| x.powi(2)).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        xs
+    } else {
+        xs.into_iter().map(|x| x * length / norm).collect()
+    }
+}
+
+/// Reflects `x` at the boundaries of `interval`.
+fn reflect(x: f64, interval: &Interval) -> f64 {
+    let (min, max) = (interval.min(), interval.max());
+    let x = if x > max {
+        2.0 * max - x
+    } else if x < min {
+        2.0 * min - x
+    } else {
+        x
+    };
+    x.clamp(min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_sampled_global_optimum;
+    use crate::{Ackley, Branin};
+
+    #[test]
+    fn moving_peaks_works() {
+        let mut f = MovingPeaks::scenario2(0);
+        let mut g = MovingPeaks::scenario2(0);
+        assert_eq!(f.dimension().get(), 5);
+        assert_eq!(f.peak_positions().count(), 10);
+        assert_eq!(f.sense(), Sense::Maximize);
+
+        let xs = [50.0; 5];
+        for t in 0..20 {
+            assert_eq!(f.time(), t);
+            assert_eq!(f.evaluate(&xs), g.evaluate(&xs));
+
+            let optimum = f.global_optimum().unwrap();
+            assert!(Interval::new(30.0, 70.0).unwrap().contains(optimum.value()));
+            for xs in optimum.inputs() {
+                assert!(f.validate(xs).is_ok());
+                assert_eq!(f.evaluate(xs), optimum.value());
+            }
+            assert!(f.evaluate(&xs) <= optimum.value());
+
+            let before = f.peak_positions().map(|p| p.to_vec()).collect::<Vec<_>>();
+            f.advance();
+            g.advance();
+            for (a, b) in before.iter().zip(f.peak_positions()) {
+                assert!(distance(a, b) <= 1.0 + 1e-9);
+            }
+        }
+
+        let h = MovingPeaks::scenario2(1);
+        assert_ne!(h.evaluate(&xs), MovingPeaks::scenario2(0).evaluate(&xs));
+    }
+
+    #[test]
+    fn drifting_works() {
+        let ackley = Ackley::new(NonZeroUsize::new(2).unwrap());
+        let mut f = Drifting::new(ackley, 0.5, 0);
+        let mut g = f.clone();
+        assert_eq!(f.shift(), &[0.0, 0.0][..]);
+
+        for t in 0..10 {
+            assert_eq!(f.time(), t);
+            let optimum = f.global_optimum().unwrap();
+            assert_eq!(optimum.inputs()[0], f.shift());
+            assert!(f.evaluate(&optimum.inputs()[0]).abs() < 1e-12);
+
+            let before = f.shift().to_vec();
+            f.advance();
+            g.advance();
+            assert_eq!(f.shift(), g.shift());
+            assert!((distance(&before, f.shift()) - 0.5).abs() < 1e-12);
+        }
+
+        let mut f = Drifting::new(Branin, 3.0, 0);
+        for _ in 0..50 {
+            assert_eq!(f.global_optimum().unwrap().inputs().len(), 3);
+            assert_sampled_global_optimum(&f, 1e-9);
+            f.advance();
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{interval, Differentiable, GlobalOptimum, Interval, Objective, Optimum};
+use std::f64::consts::PI;
+
+/// Easom Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Easom Function](https://www.sfu.ca/~ssurjano/easom.html)
+#[derive(Debug, Clone)]
+pub struct Easom;
+impl Objective for Easom {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-100.0, 100.0);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        -x.cos() * y.cos() * (-(x - PI).powi(2) - (y - PI).powi(2)).exp()
+    }
+}
+impl GlobalOptimum for Easom {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(-1.0, vec![vec![PI, PI]]))
+    }
+}
+impl Differentiable for Easom {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let e = (-(x - PI).powi(2) - (y - PI).powi(2)).exp();
+        vec![
+            e * y.cos() * (x.sin() + 2.0 * (x - PI) * x.cos()),
+            e * x.cos() * (y.sin() + 2.0 * (y - PI) * y.cos()),
+        ]
+    }
+}
+
+/// Eggholder Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Eggholder Function](https://www.sfu.ca/~ssurjano/egg.html)
+#[derive(Debug, Clone)]
+pub struct Eggholder;
+impl Objective for Eggholder {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-512.0, 512.0);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        -(y + 47.0) * (y + x / 2.0 + 47.0).abs().sqrt().sin()
+            - x * (x - (y + 47.0)).abs().sqrt().sin()
+    }
+}
+impl GlobalOptimum for Eggholder {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            -959.6406627208509,
+            vec![vec![512.0, 404.2318051137578]],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_global_optimum, assert_gradient, assert_reference_values};
+
+    #[test]
+    fn easom_works() {
+        let xs = [
+            [2.134984527644112, 4.481656085942776],
+            [1.9240134194984517, 3.3511739015003528],
+            [5.425890609183947, 0.6058766323071572],
+            [0.36966145224105507, 1.3732166414325886],
+            [4.590973447957655, 3.6925924507721826],
+        ];
+        let ys = [
+            -0.007369491797727417,
+            -0.0735270706952029,
+            -4.7007424398208106e-06,
+            -3.693912218326735e-06,
+            -0.009321170520956991,
+        ];
+
+        let f = Easom;
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn eggholder_works() {
+        let xs = [
+            [-268.8848180988663, -172.9873967393437],
+            [-330.1993538595257, -41.96479777773993],
+            [-468.1613437112119, 202.02688661659488],
+            [405.4300458105064, 465.65130070256134],
+            [240.51502742930006, 470.9044138116619],
+        ];
+        let ys = [
+            -207.06987155413304,
+            -170.23714538410508,
+            631.8144821678329,
+            -187.1831273887808,
+            128.53559140639936,
+        ];
+
+        let f = Eggholder;
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        assert_global_optimum(&Easom);
+        assert_global_optimum(&Eggholder);
+    }
+
+    #[test]
+    fn gradients_work() {
+        for xs in &[[0.3, -1.2], [1.7, 0.4], [-0.5, 0.9]] {
+            assert_gradient(&Easom, xs);
+        }
+    }
+}
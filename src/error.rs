@@ -0,0 +1,73 @@
+use crate::Interval;
+use std::error;
+use std::fmt;
+
+/// Possible errors.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// `min` of an interval is greater than `max` (or either is NaN).
+    InvalidInterval { min: f64, max: f64 },
+
+    /// The length of an input is different from the dimension of the objective.
+    DimensionMismatch { expected: usize, actual: usize },
+
+    /// The `index`-th element of an input is out of the input domain.
+    OutOfDomain {
+        index: usize,
+        value: f64,
+        domain: Interval,
+    },
+
+    /// The `index`-th element of an input is NaN or infinite.
+    NonFiniteInput { index: usize, value: f64 },
+
+    /// The `index`-th element of an input is not a feasible value of its variable type.
+    OffGrid { index: usize, value: f64 },
+
+    /// The output of an objective is NaN or infinite.
+    NonFiniteOutput { value: f64 },
+
+    /// A fidelity level is NaN or out of the range of valid fidelity levels.
+    FidelityOutOfRange { phi: f64, range: Interval },
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidInterval { min, max } => {
+                write!(f, "invalid interval: min={}, max={}", min, max)
+            }
+            Error::DimensionMismatch { expected, actual } => write!(
+                f,
+                "dimension mismatch: expected={}, actual={}",
+                expected, actual
+            ),
+            Error::OutOfDomain {
+                index,
+                value,
+                domain,
+            } => write!(
+                f,
+                "out of domain: xs[{}]={} is not within [{}, {}]",
+                index,
+                value,
+                domain.min(),
+                domain.max()
+            ),
+            Error::NonFiniteInput { index, value } => {
+                write!(f, "non-finite input: xs[{}]={}", index, value)
+            }
+            Error::OffGrid { index, value } => {
+                write!(f, "off grid: xs[{}]={}", index, value)
+            }
+            Error::NonFiniteOutput { value } => write!(f, "non-finite output: {}", value),
+            Error::FidelityOutOfRange { phi, range } => write!(
+                f,
+                "fidelity out of range: {} is not within [{}, {}]",
+                phi,
+                range.min(),
+                range.max()
+            ),
+        }
+    }
+}
+impl error::Error for Error {}
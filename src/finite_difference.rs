@@ -0,0 +1,89 @@
+use crate::{Differentiable, Error, Interval, Objective, Sense, SingleObjective, VariableType};
+
+/// Wrapper that makes any single objective `Differentiable` by using central finite differences.
+#[derive(Debug, Clone)]
+pub struct FiniteDifference<T> {
+    inner: T,
+    step: f64,
+}
+impl<T> FiniteDifference<T>
+where
+    T: SingleObjective,
+{
+    /// Default relative step size.
+    pub const DEFAULT_STEP: f64 = 1e-6;
+
+    /// Makes a new `FiniteDifference` instance.
+    pub const fn new(inner: T) -> Self {
+        Self {
+            inner,
+            step: Self::DEFAULT_STEP,
+        }
+    }
+
+    /// Sets the relative step size.
+    ///
+    /// The actual step size at `x` is `step * max(1, |x|)`.
+    pub const fn with_step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Returns a reference to the wrapped objective.
+    pub const fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the wrapped objective.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+impl<T> Objective for FiniteDifference<T>
+where
+    T: SingleObjective,
+{
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        self.inner.input_domain()
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        self.inner.evaluate(xs)
+    }
+
+    fn sense(&self) -> Sense {
+        self.inner.sense()
+    }
+
+    fn variable_types(&self) -> Vec<VariableType> {
+        self.inner.variable_types()
+    }
+
+    fn validate(&self, xs: &[f64]) -> Result<(), Error> {
+        self.inner.validate(xs)
+    }
+}
+impl<T> Differentiable for FiniteDifference<T>
+where
+    T: SingleObjective,
+{
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        let mut xs = xs.to_vec();
+        (0..xs.len())
+            .map(|i| {
+                let x = xs[i];
+                let h = self.step * x.abs().max(1.0);
+
+                xs[i] = x + h;
+                let y0 = self.inner.evaluate(&xs);
+                xs[i] = x - h;
+                let y1 = self.inner.evaluate(&xs);
+                xs[i] = x;
+
+                (y0 - y1) / (2.0 * h)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{interval, Differentiable, GlobalOptimum, Interval, Objective, Optimum};
+use std::num::NonZeroUsize;
+
+/// Goldstein-Price Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Goldstein-Price Function](https://www.sfu.ca/~ssurjano/goldpr.html)
+#[derive(Debug, Clone)]
+pub struct GoldsteinPrice;
+impl GoldsteinPrice {
+    /// Returns the value and the gradient of the first factor.
+    fn a(x: f64, y: f64) -> (f64, [f64; 2]) {
+        let u = x + y + 1.0;
+        let p = 19.0 - 14.0 * x + 3.0 * x.powi(2) - 14.0 * y + 6.0 * x * y + 3.0 * y.powi(2);
+        let dp = -14.0 + 6.0 * x + 6.0 * y;
+        let d = 2.0 * u * p + u.powi(2) * dp;
+        (1.0 + u.powi(2) * p, [d, d])
+    }
+
+    /// Returns the value and the gradient of the second factor.
+    fn b(x: f64, y: f64) -> (f64, [f64; 2]) {
+        let v = 2.0 * x - 3.0 * y;
+        let q = 18.0 - 32.0 * x + 12.0 * x.powi(2) + 48.0 * y - 36.0 * x * y + 27.0 * y.powi(2);
+        (
+            30.0 + v.powi(2) * q,
+            [
+                4.0 * v * q + v.powi(2) * (-32.0 + 24.0 * x - 36.0 * y),
+                -6.0 * v * q + v.powi(2) * (48.0 - 36.0 * x + 54.0 * y),
+            ],
+        )
+    }
+}
+impl Objective for GoldsteinPrice {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-2.0, 2.0);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let (a, _) = Self::a(x, y);
+        let (b, _) = Self::b(x, y);
+        a * b
+    }
+}
+impl GlobalOptimum for GoldsteinPrice {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(3.0, vec![vec![0.0, -1.0]]))
+    }
+}
+impl Differentiable for GoldsteinPrice {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let (a, da) = Self::a(x, y);
+        let (b, db) = Self::b(x, y);
+        vec![da[0] * b + a * db[0], da[1] * b + a * db[1]]
+    }
+}
+
+/// Griewank Function.
+///
+/// # References
+///
+/// - [BenchmarkFcns: Griewank Function](http://benchmarkfcns.xyz/benchmarkfcns/griewankfcn.html)
+#[derive(Debug, Clone)]
+pub struct Griewank {
+    input_domain: Vec<Interval>,
+}
+impl Griewank {
+    /// Makes a new `Griewank` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get())
+            .map(|_| interval(-600.0, 600.0))
+            .collect();
+        Self { input_domain }
+    }
+}
+impl Objective for Griewank {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let sum = xs.iter().map(|&x| x * x).sum::<f64>() / 4000.0;
+        let product = xs
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| (x / ((i + 1) as f64).sqrt()).cos())
+            .product::<f64>();
+        1.0 + sum - product
+    }
+}
+impl GlobalOptimum for Griewank {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0; self.dimension().get()]]))
+    }
+}
+impl Differentiable for Griewank {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let scales = (1..=xs.len())
+            .map(|i| (i as f64).sqrt())
+            .collect::<Vec<_>>();
+        (0..xs.len())
+            .map(|i| {
+                let product = xs
+                    .iter()
+                    .zip(scales.iter())
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(_, (&x, &s))| (x / s).cos())
+                    .product::<f64>();
+                xs[i] / 2000.0 + (xs[i] / scales[i]).sin() / scales[i] * product
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_global_optimum, assert_gradient, assert_reference_values};
+
+    #[test]
+    fn griewank_works() {
+        let xs = [
+            [-149.95687063528612, 382.884683804245, 421.5822440364834],
+            [185.08845414624295, -498.3156426540518, 124.40582033418593],
+            [-329.17792637271066, 256.97320698787473, 94.65453530388027],
+            [
+                -419.93449260423165,
+                -461.97121811877855,
+                -103.54390320334727,
+            ],
+            [488.3062665262412, -163.1412464915291, 231.24957622156023],
+        ];
+        let ys = [
+            87.74590261981272,
+            74.74551756139482,
+            46.61994975082543,
+            101.62499783240258,
+            80.63285157916009,
+        ];
+
+        let f = Griewank::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        assert_global_optimum(&Griewank::new(NonZeroUsize::new(5).unwrap()));
+        assert_global_optimum(&GoldsteinPrice);
+    }
+
+    #[test]
+    fn gradients_work() {
+        assert_gradient(
+            &Griewank::new(NonZeroUsize::new(3).unwrap()),
+            &[0.3, -12.0, 25.0],
+        );
+        for xs in &[[0.3, -1.2], [1.7, 0.4], [-0.5, 0.9]] {
+            assert_gradient(&GoldsteinPrice, xs);
+        }
+    }
+
+    #[test]
+    fn goldstein_price_works() {
+        let xs = [
+            [-1.9272498988267701, -0.8440141283966272],
+            [1.8640270278724915, 1.100957740900594],
+            [-0.358289275724498, 1.7732334694119252],
+            [0.48204188585411645, 1.2717112027930106],
+            [-0.8263589853724946, -1.2343391807650428],
+        ];
+        let ys = [
+            27412.45433593659,
+            1983.2377974524757,
+            256348.97666317152,
+            21512.761076220115,
+            1437.2134404655264,
+        ];
+
+        let f = GoldsteinPrice;
+        assert_reference_values(&f, &xs, &ys);
+    }
+}
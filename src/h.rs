@@ -0,0 +1,68 @@
+use crate::{interval, GlobalOptimum, Interval, Objective, Optimum};
+use std::f64::consts::PI;
+
+/// Holder Table Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Holder Table Function](https://www.sfu.ca/~ssurjano/holder.html)
+#[derive(Debug, Clone)]
+pub struct HolderTable;
+impl Objective for HolderTable {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-10.0, 10.0);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let r = (x.powi(2) + y.powi(2)).sqrt();
+        -(x.sin() * y.cos() * (1.0 - r / PI).abs().exp()).abs()
+    }
+}
+impl GlobalOptimum for HolderTable {
+    fn global_optimum(&self) -> Option<Optimum> {
+        const X: f64 = 8.055023475736563;
+        const Y: f64 = 9.664590019241272;
+        Some(Optimum::new(
+            -19.208502567886732,
+            vec![vec![X, Y], vec![X, -Y], vec![-X, Y], vec![-X, -Y]],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_global_optimum, assert_reference_values};
+
+    #[test]
+    fn holder_table_works() {
+        let xs = [
+            [-1.117155210376481, -7.2712474688817],
+            [-2.367307305513373, 9.236272448138095],
+            [-3.373855416016271, -9.812070673208709],
+            [-9.104056141557495, -6.608659123600898],
+            [5.674913496427099, -2.7455146614931136],
+        ];
+        let ys = [
+            -1.8922404442670062,
+            -5.2558649466645,
+            -2.1316837209298685,
+            -3.9456451299709014,
+            -1.4427052400440878,
+        ];
+
+        let f = HolderTable;
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        assert_global_optimum(&HolderTable);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{interval, Differentiable, GlobalOptimum, Interval, Objective, Optimum};
+use std::f64::consts::PI;
+use std::num::NonZeroUsize;
+
+/// Levy Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Levy Function](https://www.sfu.ca/~ssurjano/levy.html)
+#[derive(Debug, Clone)]
+pub struct Levy {
+    input_domain: Vec<Interval>,
+}
+impl Levy {
+    /// Makes a new `Levy` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get())
+            .map(|_| interval(-10.0, 10.0))
+            .collect();
+        Self { input_domain }
+    }
+}
+impl Objective for Levy {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let ws = xs
+            .iter()
+            .map(|&x| 1.0 + (x - 1.0) / 4.0)
+            .collect::<Vec<_>>();
+        let (&first, &last) = (&ws[0], &ws[ws.len() - 1]);
+        let middle = ws[..ws.len() - 1]
+            .iter()
+            .map(|&w| (w - 1.0).powi(2) * (1.0 + 10.0 * (PI * w + 1.0).sin().powi(2)))
+            .sum::<f64>();
+        (PI * first).sin().powi(2)
+            + middle
+            + (last - 1.0).powi(2) * (1.0 + (2.0 * PI * last).sin().powi(2))
+    }
+}
+impl GlobalOptimum for Levy {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![1.0; self.dimension().get()]]))
+    }
+}
+impl Differentiable for Levy {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let n = xs.len();
+        xs.iter()
+            .enumerate()
+            .map(|(i, &x)| {
+                let w = 1.0 + (x - 1.0) / 4.0;
+                let mut g = 0.0;
+                if i == 0 {
+                    g += PI * (2.0 * PI * w).sin();
+                }
+                if i + 1 < n {
+                    let s = PI * w + 1.0;
+                    g += 2.0 * (w - 1.0) * (1.0 + 10.0 * s.sin().powi(2))
+                        + (w - 1.0).powi(2) * 10.0 * PI * (2.0 * s).sin();
+                }
+                if i + 1 == n {
+                    g += 2.0 * (w - 1.0) * (1.0 + (2.0 * PI * w).sin().powi(2))
+                        + (w - 1.0).powi(2) * 2.0 * PI * (4.0 * PI * w).sin();
+                }
+                g / 4.0
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_global_optimum, assert_gradient, assert_reference_values};
+
+    #[test]
+    fn levy_works() {
+        let xs = [
+            [8.280509306789337, -6.1089000281847206, 0.2256525861544887],
+            [7.0290935893983395, -1.2096015462990888, 4.320429646525902],
+            [9.870553415920963, 2.5678536258005984, -5.800216509563787],
+            [8.544012283256322, -6.074549742380528, 5.467234061360431],
+            [-6.469919616439155, -4.070771271693843, 9.005892055393751],
+        ];
+        let ys = [
+            43.77159493116226,
+            12.33306735352182,
+            60.48809803015839,
+            51.88945885214647,
+            43.71649978166332,
+        ];
+
+        let f = Levy::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        assert_global_optimum(&Levy::new(NonZeroUsize::new(1).unwrap()));
+        assert_global_optimum(&Levy::new(NonZeroUsize::new(5).unwrap()));
+    }
+
+    #[test]
+    fn gradients_work() {
+        assert_gradient(&Levy::new(NonZeroUsize::new(1).unwrap()), &[0.3]);
+        assert_gradient(&Levy::new(NonZeroUsize::new(3).unwrap()), &[0.3, -1.2, 2.5]);
+    }
+}
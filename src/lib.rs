@@ -4,13 +4,57 @@
 //!
 //! - [A Literature Survey of Benchmark Functions For Global Optimization Problems](https://arxiv.org/abs/1308.4008)
 //! - [BenchmarkFcns](http://http://benchmarkfcns.xyz/fcns)
-pub use self::a::{Ackley, AckleyN2, AckleyN3, AckleyN4};
+pub use self::a::{Ackley, AckleyN2, AckleyN3, AckleyN4, Adjiman, AlpineN1, AlpineN2, Amgm};
+pub use self::b::{Beale, Booth, Branin, BukinN6};
+pub use self::c::CrossInTray;
+pub use self::d::{DixonPrice, DropWave};
+pub use self::discrete::{Discretized, OffGridPolicy, VariableType};
+pub use self::e::{Easom, Eggholder};
+pub use self::error::Error;
+pub use self::finite_difference::FiniteDifference;
+pub use self::g::{GoldsteinPrice, Griewank};
+pub use self::h::HolderTable;
+pub use self::l::Levy;
+pub use self::m::{Matyas, McCormick};
+pub use self::p::Perm0DBeta;
+pub use self::r::{Rastrigin, Rosenbrock, RotatedHyperEllipsoid};
+pub use self::s::{
+    Salomon, SchafferN2, SchafferN4, Schwefel226, SixHumpCamel, Sphere, StyblinskiTang,
+    SumOfDifferentPowers, SumSquares,
+};
+pub use self::sense::{Sense, WithSense};
+pub use self::t::{ThreeHumpCamel, Trid};
+pub use self::z::Zakharov;
 use std::num::NonZeroUsize;
 
+pub mod bbob;
+pub mod constrained;
+pub mod dynamic;
 pub mod mfb;
 pub mod mfso;
+pub mod noise;
+pub mod transform;
 
 mod a;
+mod b;
+mod c;
+mod d;
+mod discrete;
+mod e;
+mod error;
+mod finite_difference;
+mod g;
+mod h;
+mod l;
+mod m;
+mod p;
+mod r;
+mod s;
+mod sense;
+mod t;
+#[cfg(test)]
+pub(crate) mod test_util;
+mod z;
 
 pub trait Objective {
     type Output;
@@ -21,26 +65,116 @@ pub trait Objective {
     fn dimension(&self) -> NonZeroUsize {
         NonZeroUsize::new(self.input_domain().len()).unwrap_or_else(|| panic!())
     }
+
+    /// Returns the optimization direction of this objective.
+    ///
+    /// The default implementation returns `Sense::Minimize`.
+    fn sense(&self) -> Sense {
+        Sense::Minimize
+    }
+
+    /// Returns the types of the input variables.
+    ///
+    /// The default implementation returns `VariableType::Continuous` for all the variables.
+    fn variable_types(&self) -> Vec<VariableType> {
+        vec![VariableType::Continuous; self.dimension().get()]
+    }
+
+    /// Evaluates a batch of points.
+    ///
+    /// `xs` is a row-major block of points, and their outputs are appended to `outputs` in the same order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `xs.len()` is not a multiple of `self.dimension()`.
+    fn evaluate_batch(&self, xs: &[f64], outputs: &mut Vec<Self::Output>) {
+        let dimension = self.dimension().get();
+        assert_eq!(xs.len() % dimension, 0);
+        outputs.extend(xs.chunks_exact(dimension).map(|xs| self.evaluate(xs)));
+    }
+
+    /// Parallel version of `evaluate_batch`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `xs.len()` is not a multiple of `self.dimension()`.
+    #[cfg(feature = "parallel")]
+    fn par_evaluate_batch(&self, xs: &[f64], outputs: &mut Vec<Self::Output>)
+    where
+        Self: Sync,
+        Self::Output: Send,
+    {
+        use rayon::prelude::*;
+
+        let dimension = self.dimension().get();
+        assert_eq!(xs.len() % dimension, 0);
+        outputs.par_extend(xs.par_chunks_exact(dimension).map(|xs| self.evaluate(xs)));
+    }
+
+    /// Checks whether `xs` is a valid input for this objective.
+    ///
+    /// `xs` is valid if its length equals to the dimension and all the elements are finite and within the input domain.
+    fn validate(&self, xs: &[f64]) -> Result<(), Error> {
+        validate_domain(self.input_domain(), xs)
+    }
 }
 
-pub trait GlobalOptimumInput {
-    fn global_optimum_input(&self) -> &[f64];
+/// Objective whose global optimum is known.
+pub trait GlobalOptimum {
+    /// Returns the global optimum of this objective.
+    ///
+    /// Returns `None` if it is not known for the current configuration (e.g., dimension).
+    fn global_optimum(&self) -> Option<Optimum>;
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Global optimum value and all the inputs attaining it.
+///
+/// If the optimum value is attained on a continuum (e.g., a line segment),
+/// the inputs are finitely many representatives of it, and the implementation documents how they represent the whole set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Optimum {
+    value: f64,
+    inputs: Vec<Vec<f64>>,
+}
+impl Optimum {
+    /// Makes a new `Optimum` instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `inputs` is empty.
+    pub fn new(value: f64, inputs: Vec<Vec<f64>>) -> Self {
+        assert!(!inputs.is_empty());
+        Self { value, inputs }
+    }
+
+    /// Returns the optimum value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the inputs attaining the optimum value.
+    pub fn inputs(&self) -> &[Vec<f64>] {
+        &self.inputs
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Interval {
     min: f64,
     max: f64,
 }
 impl Interval {
-    pub fn new(min: f64, max: f64) -> Option<Self> {
+    pub fn new(min: f64, max: f64) -> Result<Self, Error> {
         if min <= max {
-            Some(Self { min, max })
+            Ok(Self { min, max })
         } else {
-            None
+            Err(Error::InvalidInterval { min, max })
         }
     }
 
+    /// # Safety
+    ///
+    /// `min` must be less than or equal to `max`.
     pub const unsafe fn new_unchecked(min: f64, max: f64) -> Self {
         Self { min, max }
     }
@@ -52,13 +186,138 @@ impl Interval {
     pub const fn max(&self) -> f64 {
         self.max
     }
+
+    /// Returns `true` if `x` is within this interval (both ends inclusive).
+    pub fn contains(&self, x: f64) -> bool {
+        self.min <= x && x <= self.max
+    }
 }
 
-pub trait SingleObjective {
-    fn input_domain(&self) -> &[Interval];
-    fn evaluate(&self, xs: &[f64]) -> f64;
+/// Checks whether the length of `xs` equals to the length of `domain` and all the elements are finite and within `domain`.
+pub(crate) fn validate_domain(domain: &[Interval], xs: &[f64]) -> Result<(), Error> {
+    if xs.len() != domain.len() {
+        return Err(Error::DimensionMismatch {
+            expected: domain.len(),
+            actual: xs.len(),
+        });
+    }
+    for (index, (&value, &domain)) in xs.iter().zip(domain.iter()).enumerate() {
+        if !value.is_finite() {
+            return Err(Error::NonFiniteInput { index, value });
+        }
+        if !domain.contains(value) {
+            return Err(Error::OutOfDomain {
+                index,
+                value,
+                domain,
+            });
+        }
+    }
+    Ok(())
+}
 
-    fn dimension(&self) -> NonZeroUsize {
-        NonZeroUsize::new(self.input_domain().len()).unwrap_or_else(|| panic!())
+/// Makes an `Interval` in constant contexts (e.g., the input domains of the test functions).
+///
+/// `low` must be less than or equal to `high`.
+pub(crate) const fn interval(low: f64, high: f64) -> Interval {
+    unsafe { Interval::new_unchecked(low, high) }
+}
+
+/// Objective that outputs a single value.
+///
+/// This trait is automatically implemented for every `Objective<Output = f64>`.
+pub trait SingleObjective: Objective<Output = f64> {
+    /// Fallible version of `evaluate`.
+    ///
+    /// Unlike `evaluate`, this never panics on invalid inputs, and also reports non-finite outputs as errors.
+    fn try_evaluate(&self, xs: &[f64]) -> Result<f64, Error> {
+        self.validate(xs)?;
+        let value = self.evaluate(xs);
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(Error::NonFiniteOutput { value })
+        }
+    }
+}
+impl<T> SingleObjective for T where T: ?Sized + Objective<Output = f64> {}
+
+/// Single objective that provides its gradient.
+pub trait Differentiable: SingleObjective {
+    /// Returns the gradient at `xs`.
+    fn gradient(&self, xs: &[f64]) -> Vec<f64>;
+
+    /// Returns the value and the gradient at `xs`.
+    fn value_and_gradient(&self, xs: &[f64]) -> (f64, Vec<f64>) {
+        (self.evaluate(xs), self.gradient(xs))
+    }
+}
+
+/// Objective whose outputs are random.
+pub trait StochasticObjective: Objective {
+    /// Evaluates `xs` by using the random numbers determined by `seed`.
+    ///
+    /// Evaluations with the same seed share the same random numbers,
+    /// which allows to compare different inputs under the same noise realization (i.e., common random numbers).
+    fn evaluate_with_seed(&self, xs: &[f64], seed: u64) -> Self::Output;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interval_works() {
+        assert!(Interval::new(0.0, 1.0).is_ok());
+        assert!(Interval::new(1.0, 1.0).is_ok());
+        assert_eq!(
+            Interval::new(1.0, 0.0),
+            Err(Error::InvalidInterval { min: 1.0, max: 0.0 })
+        );
+        assert!(Interval::new(f64::NAN, 0.0).is_err());
+    }
+
+    #[test]
+    fn evaluate_batch_works() {
+        let f = Ackley::new(NonZeroUsize::new(2).unwrap());
+        let xs = [0.0, 0.0, 1.0, 2.0, -3.0, 4.0];
+        let mut ys = Vec::new();
+        f.evaluate_batch(&xs, &mut ys);
+        assert_eq!(ys.len(), 3);
+        for (xs, &y) in xs.chunks(2).zip(ys.iter()) {
+            assert_eq!(f.evaluate(xs), y);
+        }
+
+        #[cfg(feature = "parallel")]
+        {
+            let mut par_ys = Vec::new();
+            f.par_evaluate_batch(&xs, &mut par_ys);
+            assert_eq!(ys, par_ys);
+        }
+    }
+
+    #[test]
+    fn try_evaluate_works() {
+        let f = Adjiman;
+        assert!(f.try_evaluate(&[2.0, 0.1]).is_ok());
+        assert_eq!(
+            f.try_evaluate(&[2.0]),
+            Err(Error::DimensionMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            f.try_evaluate(&[3.0, 0.1]),
+            Err(Error::OutOfDomain {
+                index: 0,
+                value: 3.0,
+                domain: f.input_domain()[0]
+            })
+        );
+        assert!(matches!(
+            f.try_evaluate(&[0.0, f64::NAN]),
+            Err(Error::NonFiniteInput { index: 1, .. })
+        ));
     }
 }
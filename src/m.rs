@@ -0,0 +1,140 @@
+use crate::{interval, Differentiable, GlobalOptimum, Interval, Objective, Optimum};
+
+/// Matyas Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Matyas Function](https://www.sfu.ca/~ssurjano/matya.html)
+#[derive(Debug, Clone)]
+pub struct Matyas;
+impl Objective for Matyas {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-10.0, 10.0);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        0.26 * (x.powi(2) + y.powi(2)) - 0.48 * x * y
+    }
+}
+impl GlobalOptimum for Matyas {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0, 0.0]]))
+    }
+}
+impl Differentiable for Matyas {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        vec![0.52 * x - 0.48 * y, 0.52 * y - 0.48 * x]
+    }
+}
+
+/// McCormick Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: McCormick Function](https://www.sfu.ca/~ssurjano/mccorm.html)
+#[derive(Debug, Clone)]
+pub struct McCormick;
+impl Objective for McCormick {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-1.5, 4.0);
+        const Y: Interval = interval(-3.0, 4.0);
+        &[X, Y]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        (x + y).sin() + (x - y).powi(2) - 1.5 * x + 2.5 * y + 1.0
+    }
+}
+impl GlobalOptimum for McCormick {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            -1.9132229549810364,
+            vec![vec![-0.5471975511965977, -1.5471975511965979]],
+        ))
+    }
+}
+impl Differentiable for McCormick {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let c = (x + y).cos();
+        vec![c + 2.0 * (x - y) - 1.5, c - 2.0 * (x - y) + 2.5]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_global_optimum, assert_gradient, assert_reference_values};
+
+    #[test]
+    fn matyas_works() {
+        let xs = [
+            [-4.193315271816291, -8.05795573602218],
+            [9.634972990637529, -1.5209496145651045],
+            [-5.841663164851334, -8.813209524421515],
+            [-8.894587496351985, -6.626594573924716],
+            [3.536542351636111, -7.00718930266537],
+        ];
+        let ys = [
+            5.234797892469411,
+            31.772026046792273,
+            4.35517487715665,
+            3.6950189026318356,
+            27.91302320206665,
+        ];
+
+        let f = Matyas;
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn mc_cormick_works() {
+        let xs = [
+            [-1.2750919833461554, 0.4346733099122009],
+            [-0.13017741763680268, 3.9834556896166324],
+            [-0.8274970830106925, 0.7046911049660429],
+            [2.7558566889388976, -0.13475138275857912],
+            [3.9321156151699856, 0.3443337411612952],
+        ];
+        let ys = [
+            6.177696104177834,
+            27.42277153847815,
+            6.228076498716072,
+            5.382254542107536,
+            7.928366030940189,
+        ];
+
+        let f = McCormick;
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        assert_global_optimum(&Matyas);
+        assert_global_optimum(&McCormick);
+    }
+
+    #[test]
+    fn gradients_work() {
+        for xs in &[[0.3, -1.2], [1.7, 0.4], [-0.5, 0.9]] {
+            assert_gradient(&Matyas, xs);
+            assert_gradient(&McCormick, xs);
+        }
+    }
+}
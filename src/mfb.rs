@@ -1,46 +1,269 @@
-use crate::{Interval, Objective};
+//! **M**ulti-**F**idelity **B**enchmark functions.
+//!
+//! # References
+//!
+//! - H. Wang, Y. Jin and J. Doherty, "A Generic Test Suite for Evolutionary Multifidelity Optimization",
+//!   IEEE Transactions on Evolutionary Computation, 2018
+use crate::mfso::{self, MultiFidelitySingleObjective, Outputs};
+use crate::{
+    Differentiable, Error, GlobalOptimum, Interval, Objective, Optimum, Sense, SingleObjective,
+    StochasticObjective, VariableType,
+};
 use rand::distributions::{Distribution, Normal};
-use rand::{self, Rng as _};
+use rand::rngs::StdRng;
+use rand::{FromEntropy, Rng, SeedableRng};
 use std::f64::consts::PI;
-use std::num::NonZeroUsize;
-
-pub type FidelityLevel = f64; // 0..10000
-
+use std::num::{NonZeroU64, NonZeroUsize};
+use std::sync::Mutex;
+
+pub mod suite;
+
+pub type FidelityLevel = f64; // 0..=10000
+
+/// Range of valid fidelity levels.
+pub const FIDELITY_RANGE: Interval = unsafe { Interval::new_unchecked(0.0, 10_000.0) };
+
+/// Multi-fidelity objective that can be evaluated at an arbitrary fidelity level.
+pub trait ContinuousFidelity: MultiFidelitySingleObjective {
+    /// Returns the range of fidelity levels at which this objective can be evaluated.
+    fn fidelity_range(&self) -> Interval {
+        FIDELITY_RANGE
+    }
+
+    /// Evaluates `xs` at the fidelity level `phi`, and returns the cost and the value.
+    ///
+    /// Like `SingleObjective::try_evaluate`, this returns an error if `xs` is invalid or the value is not finite.
+    /// It also returns `Error::FidelityOutOfRange` if `phi` is out of `fidelity_range()`.
+    fn evaluate_at_fidelity(
+        &self,
+        xs: &[f64],
+        phi: FidelityLevel,
+    ) -> Result<(mfso::Cost, f64), Error>;
+}
+
+/// Multi-fidelity benchmark function.
+///
+/// The value at fidelity level `phi` is `f(xs) + e.error(xs, phi) + s.error(xs, phi) + i.error(xs, phi)`
+/// and its cost is `c.cost(phi)`.
+///
+/// Each of the error kinds can be disabled by using `NoError`.
+///
+/// The random numbers used by the stochastic and instability errors are drawn from
+/// an internal RNG, which is seeded from entropy by default (see `with_seed`).
+/// `evaluate_with_rng` can be used to supply an RNG per evaluation instead.
 #[derive(Debug)]
-pub struct Mfb<F, E, C> {
+pub struct Mfb<F, E, C, S = NoError, I = NoError> {
     f: F,
     e: E,
     c: C,
+    s: S,
+    i: I,
     levels: Vec<FidelityLevel>,
+    rng: Mutex<StdRng>,
 }
 impl<F, E, C> Mfb<F, E, C>
 where
-    F: Objective,
+    F: SingleObjective,
     E: ResolutionError,
     C: Cost,
 {
-    pub fn new(f: F, e: E, c: C, levels: Vec<FidelityLevel>) -> Self {
-        Self { f, e, c, levels }
+    /// Makes a new `Mfb` instance.
+    ///
+    /// `levels` are sorted in ascending order, so the last output of `evaluate` is the highest fidelity one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `levels` is empty or contains a level out of `FIDELITY_RANGE`.
+    pub fn new(f: F, e: E, c: C, mut levels: Vec<FidelityLevel>) -> Self {
+        assert!(!levels.is_empty());
+        assert!(levels.iter().all(|&phi| FIDELITY_RANGE.contains(phi)));
+        levels.sort_by(|a, b| a.partial_cmp(b).unwrap_or_else(|| panic!()));
+        Self {
+            f,
+            e,
+            c,
+            s: NoError,
+            i: NoError,
+            levels,
+            rng: Mutex::new(StdRng::from_entropy()),
+        }
     }
 }
-// impl<F, E, C> Objective for Mfb<F, E, C>
-// where
-//     F: Objective,
-//     E: ResolutionError,
-//     C: Cost,
-// {
-//     type Output = Outputs;
+impl<F, E, C, S, I> Mfb<F, E, C, S, I>
+where
+    F: SingleObjective,
+    E: ResolutionError,
+    C: Cost,
+    S: StochasticError,
+    I: InstabilityError,
+{
+    /// Replaces the stochastic error of this instance with `s`.
+    pub fn with_stochastic_error<T>(self, s: T) -> Mfb<F, E, C, T, I>
+    where
+        T: StochasticError,
+    {
+        Mfb {
+            f: self.f,
+            e: self.e,
+            c: self.c,
+            s,
+            i: self.i,
+            levels: self.levels,
+            rng: self.rng,
+        }
+    }
 
-//     fn input_domain(&self) -> &[Interval] {
-//         self.f.input_domain()
-//     }
+    /// Replaces the instability error of this instance with `i`.
+    pub fn with_instability_error<T>(self, i: T) -> Mfb<F, E, C, S, T>
+    where
+        T: InstabilityError,
+    {
+        Mfb {
+            f: self.f,
+            e: self.e,
+            c: self.c,
+            s: self.s,
+            i,
+            levels: self.levels,
+            rng: self.rng,
+        }
+    }
 
-//     fn evaluate(&self, xs: &[f64]) -> Self::Output {
-//         self.f.evaluate(xs) + self.e.error(xs, phi)
-//     }
-// }
+    /// Reseeds the internal RNG of this instance with `seed`.
+    ///
+    /// Two instances having the same configuration and seed produce identical sequences of outputs.
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self {
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            ..self
+        }
+    }
 
-#[derive(Debug)]
+    /// Evaluates `xs` by using `rng` as the source of randomness instead of the internal RNG.
+    pub fn evaluate_with_rng<R>(&self, xs: &[f64], rng: &mut R) -> Outputs
+    where
+        R: Rng + ?Sized,
+    {
+        let y = self.f.evaluate(xs);
+        let outputs = self
+            .levels
+            .iter()
+            .map(|&phi| (self.level_cost(phi), y + self.error(xs, phi, rng)))
+            .collect::<Vec<_>>();
+        Outputs::new(outputs.into_iter())
+    }
+
+    /// Evaluates `xs` at the fidelity level `phi` by using `rng` as the source of randomness.
+    ///
+    /// Errors are reported in the same way as `ContinuousFidelity::evaluate_at_fidelity`.
+    pub fn evaluate_at_fidelity_with_rng<R>(
+        &self,
+        xs: &[f64],
+        phi: FidelityLevel,
+        rng: &mut R,
+    ) -> Result<(mfso::Cost, f64), Error>
+    where
+        R: Rng + ?Sized,
+    {
+        self.f.validate(xs)?;
+        if !FIDELITY_RANGE.contains(phi) {
+            return Err(Error::FidelityOutOfRange {
+                phi,
+                range: FIDELITY_RANGE,
+            });
+        }
+        let y = self.f.evaluate(xs) + self.error(xs, phi, rng);
+        if !y.is_finite() {
+            return Err(Error::NonFiniteOutput { value: y });
+        }
+        Ok((self.level_cost(phi), y))
+    }
+
+    fn error<R>(&self, xs: &[f64], phi: FidelityLevel, rng: &mut R) -> f64
+    where
+        R: Rng + ?Sized,
+    {
+        self.e.error(xs, phi) + self.s.error(xs, phi, rng) + self.i.error(xs, phi, rng)
+    }
+
+    fn level_cost(&self, phi: FidelityLevel) -> mfso::Cost {
+        NonZeroU64::new(self.c.cost(phi)).unwrap_or(NonZeroU64::new(1).unwrap())
+    }
+}
+impl<F, E, C, S, I> Objective for Mfb<F, E, C, S, I>
+where
+    F: SingleObjective,
+    E: ResolutionError,
+    C: Cost,
+    S: StochasticError,
+    I: InstabilityError,
+{
+    type Output = Outputs;
+
+    fn input_domain(&self) -> &[Interval] {
+        self.f.input_domain()
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> Self::Output {
+        let mut rng = self.rng.lock().unwrap_or_else(|e| e.into_inner());
+        self.evaluate_with_rng(xs, &mut *rng)
+    }
+
+    fn sense(&self) -> Sense {
+        self.f.sense()
+    }
+    fn variable_types(&self) -> Vec<VariableType> {
+        self.f.variable_types()
+    }
+
+    fn validate(&self, xs: &[f64]) -> Result<(), Error> {
+        self.f.validate(xs)
+    }
+}
+impl<F, E, C, S, I> MultiFidelitySingleObjective for Mfb<F, E, C, S, I>
+where
+    F: SingleObjective,
+    E: ResolutionError,
+    C: Cost,
+    S: StochasticError,
+    I: InstabilityError,
+{
+    fn max_cost(&self) -> mfso::Cost {
+        let phi = self.levels[self.levels.len() - 1];
+        self.level_cost(phi)
+    }
+}
+impl<F, E, C, S, I> StochasticObjective for Mfb<F, E, C, S, I>
+where
+    F: SingleObjective,
+    E: ResolutionError,
+    C: Cost,
+    S: StochasticError,
+    I: InstabilityError,
+{
+    fn evaluate_with_seed(&self, xs: &[f64], seed: u64) -> Self::Output {
+        self.evaluate_with_rng(xs, &mut StdRng::seed_from_u64(seed))
+    }
+}
+impl<F, E, C, S, I> ContinuousFidelity for Mfb<F, E, C, S, I>
+where
+    F: SingleObjective,
+    E: ResolutionError,
+    C: Cost,
+    S: StochasticError,
+    I: InstabilityError,
+{
+    fn evaluate_at_fidelity(
+        &self,
+        xs: &[f64],
+        phi: FidelityLevel,
+    ) -> Result<(mfso::Cost, f64), Error> {
+        let mut rng = self.rng.lock().unwrap_or_else(|e| e.into_inner());
+        self.evaluate_at_fidelity_with_rng(xs, phi, &mut *rng)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct ModifiedRastrigin {
     input_domain: Vec<Interval>,
 }
@@ -65,6 +288,18 @@ impl Objective for ModifiedRastrigin {
             .sum()
     }
 }
+impl GlobalOptimum for ModifiedRastrigin {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0; self.input_domain.len()]]))
+    }
+}
+impl Differentiable for ModifiedRastrigin {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        xs.iter()
+            .map(|&x| 2.0 * x + 10.0 * PI * (10.0 * PI * x).sin())
+            .collect()
+    }
+}
 
 pub trait Cost {
     fn cost(&self, phi: FidelityLevel) -> u64;
@@ -86,9 +321,62 @@ impl Cost for NonLinearCost {
     }
 }
 
+/// Error model that never adds any error.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoError;
+impl ResolutionError for NoError {
+    fn a(&self, _i: usize, _x: f64, _phi: FidelityLevel) -> f64 {
+        0.0
+    }
+
+    fn w(&self, _phi: FidelityLevel) -> f64 {
+        0.0
+    }
+
+    fn b(&self, _phi: FidelityLevel) -> f64 {
+        0.0
+    }
+
+    fn error(&self, _xs: &[f64], _phi: FidelityLevel) -> f64 {
+        0.0
+    }
+}
+impl StochasticError for NoError {
+    fn mu(&self, _xs: &[f64], _phi: FidelityLevel) -> f64 {
+        0.0
+    }
+
+    fn sigma(&self, _phi: FidelityLevel) -> f64 {
+        0.0
+    }
+
+    fn error<R>(&self, _xs: &[f64], _phi: FidelityLevel, _rng: &mut R) -> f64
+    where
+        R: Rng + ?Sized,
+    {
+        0.0
+    }
+}
+impl InstabilityError for NoError {
+    fn p(&self, _phi: FidelityLevel) -> f64 {
+        0.0
+    }
+
+    fn l(&self, _xs: &[f64]) -> f64 {
+        0.0
+    }
+
+    fn error<R>(&self, _xs: &[f64], _phi: FidelityLevel, _rng: &mut R) -> f64
+    where
+        R: Rng + ?Sized,
+    {
+        0.0
+    }
+}
+
 pub trait ResolutionError {
-    /// Maximum error.
-    fn a(&self, x: f64, phi: FidelityLevel) -> f64;
+    /// Maximum error at the `i`-th dimension.
+    fn a(&self, i: usize, x: f64, phi: FidelityLevel) -> f64;
 
     /// Determines the number of local optima.
     fn w(&self, phi: FidelityLevel) -> f64;
@@ -98,7 +386,8 @@ pub trait ResolutionError {
 
     fn error(&self, xs: &[f64], phi: FidelityLevel) -> f64 {
         xs.iter()
-            .map(|&x| self.a(x, phi) * (self.w(phi) * x + self.b(phi) + PI).cos())
+            .enumerate()
+            .map(|(i, &x)| self.a(i, x, phi) * (self.w(phi) * x + self.b(phi) + PI).cos())
             .sum()
     }
 }
@@ -111,7 +400,7 @@ impl ResolutionError1 {
     }
 }
 impl ResolutionError for ResolutionError1 {
-    fn a(&self, _x: f64, phi: FidelityLevel) -> f64 {
+    fn a(&self, _i: usize, _x: f64, phi: FidelityLevel) -> f64 {
         self.theta(phi)
     }
 
@@ -132,7 +421,7 @@ impl ResolutionError2 {
     }
 }
 impl ResolutionError for ResolutionError2 {
-    fn a(&self, _x: f64, phi: FidelityLevel) -> f64 {
+    fn a(&self, _i: usize, _x: f64, phi: FidelityLevel) -> f64 {
         self.theta(phi)
     }
 
@@ -150,7 +439,7 @@ pub struct ResolutionError3;
 impl ResolutionError3 {
     fn theta(&self, phi: FidelityLevel) -> f64 {
         assert!(0.0 <= phi);
-        assert!(phi < 10_000.0);
+        assert!(phi <= 10_000.0);
 
         if phi < 1_000.0 {
             1.0 - 0.0002 * phi
@@ -176,7 +465,7 @@ impl ResolutionError3 {
     }
 }
 impl ResolutionError for ResolutionError3 {
-    fn a(&self, _x: f64, phi: FidelityLevel) -> f64 {
+    fn a(&self, _i: usize, _x: f64, phi: FidelityLevel) -> f64 {
         self.theta(phi)
     }
 
@@ -191,25 +480,37 @@ impl ResolutionError for ResolutionError3 {
 
 #[derive(Debug)]
 pub struct ResolutionError4 {
-    global_minimum: f64, // TODO: Vec<f64>
+    global_optimum: Vec<f64>,
+    input_domain: Vec<Interval>,
 }
 impl ResolutionError4 {
-    pub const fn new(global_minimum: f64) -> Self {
-        Self { global_minimum }
+    /// Makes a new `ResolutionError4` instance.
+    ///
+    /// `global_optimum` is the location of the global optimum of the base function
+    /// and `input_domain` is the input domain of the function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of `global_optimum` and `input_domain` are different.
+    pub fn new(global_optimum: Vec<f64>, input_domain: &[Interval]) -> Self {
+        assert_eq!(global_optimum.len(), input_domain.len());
+        Self {
+            global_optimum,
+            input_domain: input_domain.to_vec(),
+        }
     }
 
     fn theta(&self, phi: FidelityLevel) -> f64 {
         1.0 - 0.0001 * phi
     }
 
-    fn psi(&self, x: f64) -> f64 {
-        // TODO: consider input domain
-        1.0 - (x - self.global_minimum).abs()
+    fn psi(&self, i: usize, x: f64) -> f64 {
+        1.0 - normalized_distance(x, self.global_optimum[i], self.input_domain[i])
     }
 }
 impl ResolutionError for ResolutionError4 {
-    fn a(&self, x: f64, phi: FidelityLevel) -> f64 {
-        self.theta(phi) * self.psi(x)
+    fn a(&self, i: usize, x: f64, phi: FidelityLevel) -> f64 {
+        self.theta(phi) * self.psi(i, x)
     }
 
     fn w(&self, phi: FidelityLevel) -> f64 {
@@ -221,15 +522,27 @@ impl ResolutionError for ResolutionError4 {
     }
 }
 
+/// Distance between `x` and `xo` in the coordinate where `interval` is mapped to `[-1, 1]`.
+fn normalized_distance(x: f64, xo: f64, interval: Interval) -> f64 {
+    let half_width = (interval.max() - interval.min()) / 2.0;
+    if half_width == 0.0 {
+        0.0
+    } else {
+        (x - xo).abs() / half_width
+    }
+}
+
 pub trait StochasticError {
     fn mu(&self, xs: &[f64], phi: FidelityLevel) -> f64;
 
     fn sigma(&self, phi: FidelityLevel) -> f64;
 
-    fn error(&self, xs: &[f64], phi: FidelityLevel) -> f64 {
-        let mut rng = rand::thread_rng(); // TODO:
+    fn error<R>(&self, xs: &[f64], phi: FidelityLevel, rng: &mut R) -> f64
+    where
+        R: Rng + ?Sized,
+    {
         let distribution = Normal::new(self.mu(xs, phi), self.sigma(phi));
-        distribution.sample(&mut rng)
+        distribution.sample(rng)
     }
 }
 
@@ -270,8 +583,25 @@ impl StochasticError for StochasticError2 {
 #[derive(Debug)]
 pub struct StochasticError3 {
     global_optimum: Vec<f64>,
+    input_domain: Vec<Interval>,
 }
 impl StochasticError3 {
+    /// Makes a new `StochasticError3` instance.
+    ///
+    /// `global_optimum` is the location of the global optimum of the base function
+    /// and `input_domain` is the input domain of the function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of `global_optimum` and `input_domain` are different.
+    pub fn new(global_optimum: Vec<f64>, input_domain: &[Interval]) -> Self {
+        assert_eq!(global_optimum.len(), input_domain.len());
+        Self {
+            global_optimum,
+            input_domain: input_domain.to_vec(),
+        }
+    }
+
     fn theta(&self, phi: FidelityLevel) -> f64 {
         1.0 - 0.0001 * phi
     }
@@ -279,7 +609,8 @@ impl StochasticError3 {
     fn gamma(&self, xs: &[f64]) -> f64 {
         xs.iter()
             .zip(self.global_optimum.iter())
-            .map(|(&x, &xo)| 1.0 - (x - xo).abs())
+            .zip(self.input_domain.iter())
+            .map(|((&x, &xo), &i)| 1.0 - normalized_distance(x, xo, i))
             .sum()
     }
 }
@@ -296,8 +627,25 @@ impl StochasticError for StochasticError3 {
 #[derive(Debug)]
 pub struct StochasticError4 {
     global_optimum: Vec<f64>,
+    input_domain: Vec<Interval>,
 }
 impl StochasticError4 {
+    /// Makes a new `StochasticError4` instance.
+    ///
+    /// `global_optimum` is the location of the global optimum of the base function
+    /// and `input_domain` is the input domain of the function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of `global_optimum` and `input_domain` are different.
+    pub fn new(global_optimum: Vec<f64>, input_domain: &[Interval]) -> Self {
+        assert_eq!(global_optimum.len(), input_domain.len());
+        Self {
+            global_optimum,
+            input_domain: input_domain.to_vec(),
+        }
+    }
+
     fn theta(&self, phi: FidelityLevel) -> f64 {
         (-0.0005 * phi).exp()
     }
@@ -305,7 +653,8 @@ impl StochasticError4 {
     fn gamma(&self, xs: &[f64]) -> f64 {
         xs.iter()
             .zip(self.global_optimum.iter())
-            .map(|(&x, &xo)| 1.0 - (x - xo).abs())
+            .zip(self.input_domain.iter())
+            .map(|((&x, &xo), &i)| 1.0 - normalized_distance(x, xo, i))
             .sum()
     }
 }
@@ -323,8 +672,10 @@ pub trait InstabilityError {
 
     fn l(&self, xs: &[f64]) -> f64;
 
-    fn error(&self, xs: &[f64], phi: FidelityLevel) -> f64 {
-        let mut rng = rand::thread_rng(); // TODO
+    fn error<R>(&self, xs: &[f64], phi: FidelityLevel, rng: &mut R) -> f64
+    where
+        R: Rng + ?Sized,
+    {
         let r = rng.gen_range(0.0, 1.0);
         if r <= self.p(phi) {
             self.l(xs)
@@ -357,3 +708,167 @@ impl InstabilityError for InstabilityError2 {
         (10 * xs.len()) as f64
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mfb_works() {
+        let f = Mfb::new(
+            ModifiedRastrigin::new(NonZeroUsize::new(2).unwrap()),
+            ResolutionError1,
+            LinearCost,
+            vec![10_000.0, 0.0, 5_000.0],
+        );
+        let outputs = f.evaluate(&[0.0, 0.0]).collect::<Vec<_>>();
+        assert_eq!(outputs.len(), 3);
+        assert_eq!(outputs[0].0.get(), 1);
+        assert_eq!(outputs[1].0.get(), 5_000);
+        assert_eq!(outputs[2], (NonZeroU64::new(10_000).unwrap(), 0.0));
+        assert_eq!(f.max_cost().get(), 10_000);
+    }
+
+    #[test]
+    fn mfb_accepts_any_single_objective() {
+        let f = Mfb::new(
+            crate::Ackley::new(NonZeroUsize::new(2).unwrap()),
+            ResolutionError1,
+            LinearCost,
+            vec![10_000.0],
+        );
+        let outputs = f.evaluate(&[0.0, 0.0]).collect::<Vec<_>>();
+        assert!(outputs[0].1.abs() < 1e-12);
+
+        let f = Mfb::new(
+            crate::Discretized::new(ModifiedRastrigin::new(NonZeroUsize::new(2).unwrap()))
+                .with_variable_type(0, crate::VariableType::Integer)
+                .with_policy(crate::OffGridPolicy::Reject),
+            ResolutionError1,
+            LinearCost,
+            vec![10_000.0],
+        );
+        assert_eq!(f.variable_types()[0], crate::VariableType::Integer);
+        assert!(f.validate(&[1.0, 0.5]).is_ok());
+        assert!(f.validate(&[0.5, 0.5]).is_err());
+    }
+
+    #[test]
+    fn modified_rastrigin_gradient_works() {
+        let f = ModifiedRastrigin::new(NonZeroUsize::new(3).unwrap());
+        let xs = [0.12, -0.34, 0.56];
+        let expected = crate::FiniteDifference::new(f.clone()).gradient(&xs);
+        for (e, a) in expected.iter().zip(f.gradient(&xs).iter()) {
+            assert!((e - a).abs() < 1e-5 * e.abs().max(1.0));
+        }
+    }
+
+    #[test]
+    fn resolution_error4_works() {
+        let domain = [
+            Interval::new(0.0, 4.0).unwrap(),
+            Interval::new(-1.0, 1.0).unwrap(),
+        ];
+        let e = ResolutionError4::new(vec![1.0, -0.5], &domain);
+        assert_eq!(e.a(0, 1.0, 0.0), 1.0);
+        assert_eq!(e.a(0, 3.0, 0.0), 0.0);
+        assert_eq!(e.a(1, 0.5, 0.0), 0.0);
+        assert_eq!(e.a(1, -0.5, 5_000.0), 0.5);
+    }
+
+    #[test]
+    fn continuous_fidelity_works() {
+        let f = Mfb::new(
+            ModifiedRastrigin::new(NonZeroUsize::new(2).unwrap()),
+            ResolutionError3,
+            NonLinearCost,
+            vec![10_000.0],
+        );
+        let xs = [0.3, -0.4];
+
+        let (cost, y) = f.evaluate_at_fidelity(&xs, 10_000.0).unwrap();
+        assert_eq!(cost.get(), 10_000);
+        assert_eq!(y, f.f.evaluate(&xs));
+
+        let (cost, y) = f.evaluate_at_fidelity(&xs, 1_500.0).unwrap();
+        assert_eq!(cost.get(), 5);
+        assert_ne!(y, f.f.evaluate(&xs));
+
+        for &phi in &[-1.0, 10_000.1] {
+            assert_eq!(
+                f.evaluate_at_fidelity(&xs, phi),
+                Err(Error::FidelityOutOfRange {
+                    phi,
+                    range: FIDELITY_RANGE
+                })
+            );
+        }
+        assert!(f.evaluate_at_fidelity(&xs, f64::NAN).is_err());
+        assert_eq!(
+            f.evaluate_at_fidelity(&[0.3], 10_000.0),
+            Err(Error::DimensionMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert!(f.evaluate_at_fidelity(&[0.3, 1.5], 10_000.0).is_err());
+        assert!(f.evaluate_at_fidelity(&[0.3, f64::NAN], 10_000.0).is_err());
+    }
+
+    #[test]
+    fn mfb_with_all_errors_works() {
+        let f = Mfb::new(
+            ModifiedRastrigin::new(NonZeroUsize::new(2).unwrap()),
+            NoError,
+            LinearCost,
+            vec![0.0, 10_000.0],
+        )
+        .with_stochastic_error(StochasticError1)
+        .with_instability_error(InstabilityError1)
+        .with_seed(0);
+
+        // At the highest fidelity, every error model vanishes.
+        let outputs = f.evaluate(&[0.0, 0.0]).collect::<Vec<_>>();
+        assert_eq!(outputs[1].1, 0.0);
+
+        // At the lowest fidelity, the stochastic error is applied.
+        assert_ne!(outputs[0].1, 0.0);
+    }
+
+    #[test]
+    fn mfb_is_reproducible() {
+        let f = || {
+            Mfb::new(
+                ModifiedRastrigin::new(NonZeroUsize::new(3).unwrap()),
+                ResolutionError1,
+                LinearCost,
+                vec![0.0, 5_000.0],
+            )
+            .with_stochastic_error(StochasticError2)
+            .with_instability_error(InstabilityError2)
+        };
+        let xs = [0.1, -0.2, 0.3];
+
+        let f0 = f().with_seed(10);
+        let f1 = f().with_seed(10);
+        for _ in 0..10 {
+            assert_eq!(
+                f0.evaluate(&xs).collect::<Vec<_>>(),
+                f1.evaluate(&xs).collect::<Vec<_>>()
+            );
+        }
+
+        let f2 = f();
+        assert_eq!(
+            f2.evaluate_with_seed(&xs, 5).collect::<Vec<_>>(),
+            f2.evaluate_with_seed(&xs, 5).collect::<Vec<_>>()
+        );
+
+        let mut rng0 = StdRng::seed_from_u64(3);
+        let mut rng1 = StdRng::seed_from_u64(3);
+        assert_eq!(
+            f2.evaluate_with_rng(&xs, &mut rng0).collect::<Vec<_>>(),
+            f2.evaluate_with_rng(&xs, &mut rng1).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! Fully configured MFB problems.
+//!
+//! Each problem uses `ModifiedRastrigin` as the base function, `LinearCost` as the cost model and
+//! `LEVELS` as the fidelity levels.
+//! The problems are numbered in the order in which the error models are defined in the paper.
+//! These ids are specific to this crate and differ from the MFB1 to MFB13 numbering of the paper,
+//! whose resolution-error problems (MFB1 to MFB7) are not all covered by the error models in this module.
+//!
+//! | id | error model         |
+//! |----|---------------------|
+//! | 1  | `ResolutionError1`  |
+//! | 2  | `ResolutionError2`  |
+//! | 3  | `ResolutionError3`  |
+//! | 4  | `ResolutionError4`  |
+//! | 5  | `StochasticError1`  |
+//! | 6  | `StochasticError2`  |
+//! | 7  | `StochasticError3`  |
+//! | 8  | `StochasticError4`  |
+//! | 9  | `InstabilityError1` |
+//! | 10 | `InstabilityError2` |
+use super::{
+    ContinuousFidelity, FidelityLevel, InstabilityError1, InstabilityError2, LinearCost, Mfb,
+    ModifiedRastrigin, NoError, ResolutionError1, ResolutionError2, ResolutionError3,
+    ResolutionError4, StochasticError1, StochasticError2, StochasticError3, StochasticError4,
+};
+use crate::Objective;
+use std::num::NonZeroUsize;
+
+/// Fidelity levels used by the problems in this suite.
+pub const LEVELS: [FidelityLevel; 5] = [0.0, 2_500.0, 5_000.0, 7_500.0, 10_000.0];
+
+/// Number of the problems in this suite.
+pub const PROBLEM_COUNT: usize = 10;
+
+/// Multi-fidelity objective of a problem.
+pub type Problem = Box<dyn ContinuousFidelity + Send + Sync>;
+
+/// Makes the problem identified by `id` (`1..=PROBLEM_COUNT`).
+///
+/// Returns `None` if `id` is out of range.
+pub fn problem(id: usize, dimension: NonZeroUsize) -> Option<Problem> {
+    let f = || ModifiedRastrigin::new(dimension);
+    let levels = || LEVELS.to_vec();
+    let global_optimum = || vec![0.0; dimension.get()];
+
+    let problem: Problem = match id {
+        1 => Box::new(Mfb::new(f(), ResolutionError1, LinearCost, levels())),
+        2 => Box::new(Mfb::new(f(), ResolutionError2, LinearCost, levels())),
+        3 => Box::new(Mfb::new(f(), ResolutionError3, LinearCost, levels())),
+        4 => Box::new(Mfb::new(
+            f(),
+            ResolutionError4::new(global_optimum(), f().input_domain()),
+            LinearCost,
+            levels(),
+        )),
+        5 => Box::new(
+            Mfb::new(f(), NoError, LinearCost, levels()).with_stochastic_error(StochasticError1),
+        ),
+        6 => Box::new(
+            Mfb::new(f(), NoError, LinearCost, levels()).with_stochastic_error(StochasticError2),
+        ),
+        7 => Box::new(
+            Mfb::new(f(), NoError, LinearCost, levels())
+                .with_stochastic_error(StochasticError3::new(global_optimum(), f().input_domain())),
+        ),
+        8 => Box::new(
+            Mfb::new(f(), NoError, LinearCost, levels())
+                .with_stochastic_error(StochasticError4::new(global_optimum(), f().input_domain())),
+        ),
+        9 => Box::new(
+            Mfb::new(f(), NoError, LinearCost, levels()).with_instability_error(InstabilityError1),
+        ),
+        10 => Box::new(
+            Mfb::new(f(), NoError, LinearCost, levels()).with_instability_error(InstabilityError2),
+        ),
+        _ => return None,
+    };
+    Some(problem)
+}
+
+/// Returns an iterator over all the problems in this suite and their ids.
+pub fn problems(dimension: NonZeroUsize) -> impl Iterator<Item = (usize, Problem)> {
+    (1..=PROBLEM_COUNT).filter_map(move |id| problem(id, dimension).map(|p| (id, p)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn problems_work() {
+        let dimension = NonZeroUsize::new(2).unwrap();
+        assert_eq!(problems(dimension).count(), PROBLEM_COUNT);
+        assert!(problem(0, dimension).is_none());
+        assert!(problem(PROBLEM_COUNT + 1, dimension).is_none());
+
+        for (_, p) in problems(dimension) {
+            let outputs = p.evaluate(&[0.0, 0.0]).collect::<Vec<_>>();
+            assert_eq!(outputs.len(), LEVELS.len());
+            assert_eq!(p.max_cost().get(), 10_000);
+            assert!(p.evaluate_at_fidelity(&[0.0, 0.0], 1_234.5).is_ok());
+        }
+    }
+}
@@ -3,15 +3,23 @@
 //! # References
 //!
 //! - [Multi-fidelity Gaussian Process Bandit Optimisation](https://arxiv.org/abs/1603.06288)
-use crate::{Interval, Objective};
+//!
+//! Note that these functions are maximization problems as in the paper (see `Objective::sense`),
+//! and the global optima reported by `GlobalOptimum` are the maxima at the highest fidelity.
+//! `WithSense` can be used to convert them to minimization problems.
+use crate::{GlobalOptimum, Interval, Objective, Optimum, Sense};
 use std::f64::consts::PI;
 use std::fmt;
 use std::iter;
 use std::num::NonZeroU64;
+use std::ops::Neg;
 
 const ZERO_TO_ONE: Interval = unsafe { Interval::new_unchecked(0.0, 1.0) };
 
+// `NonZeroU64::new(..).unwrap()` is not usable in a `const` context on older compilers.
+#[allow(clippy::useless_nonzero_new_unchecked)]
 const ONE: NonZeroU64 = unsafe { NonZeroU64::new_unchecked(1) };
+#[allow(clippy::useless_nonzero_new_unchecked)]
 const TEN: NonZeroU64 = unsafe { NonZeroU64::new_unchecked(10) };
 
 pub trait MultiFidelitySingleObjective: Objective<Output = Outputs> {
@@ -25,13 +33,23 @@ pub trait MultiFidelitySingleObjective: Objective<Output = Outputs> {
     }
 }
 
+/// Multi-fidelity objective that provides the gradient at each fidelity.
+pub trait MultiFidelityDifferentiable: MultiFidelitySingleObjective {
+    /// Returns the cost and the gradient for each fidelity, in the same order as `evaluate`.
+    fn gradients(&self, xs: &[f64]) -> Vec<(Cost, Vec<f64>)>;
+}
+
 pub type Cost = NonZeroU64;
 
-pub struct Outputs(Box<dyn Iterator<Item = (Cost, f64)>>);
+/// Costs and values of an evaluation, from the lowest to the highest fidelity.
+///
+/// The underlying iterator is required to be `Send` so that the outputs can be produced by
+/// `Objective::par_evaluate_batch`.
+pub struct Outputs(Box<dyn Iterator<Item = (Cost, f64)> + Send>);
 impl Outputs {
     pub fn new<I>(inner: I) -> Self
     where
-        I: 'static + Iterator<Item = (Cost, f64)>,
+        I: 'static + Send + Iterator<Item = (Cost, f64)>,
     {
         Self(Box::new(inner))
     }
@@ -43,6 +61,13 @@ impl Iterator for Outputs {
         self.0.next()
     }
 }
+impl Neg for Outputs {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(self.map(|(c, v)| (c, -v)))
+    }
+}
 impl fmt::Debug for Outputs {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "Outputs(_)")
@@ -76,6 +101,21 @@ impl CurrinExponential {
         a * (b / c)
     }
 
+    fn f2_gradient(&self, xs: &[f64]) -> [f64; 2] {
+        let x1 = xs[0];
+        let x2 = xs[1];
+
+        let a = 1.0 - (-1.0 / 2.0 * x2).exp();
+        let b = 2300.0 * x1.powi(3) + 1900.0 * x1.powi(2) + 2092.0 * x1 + 60.0;
+        let c = 100.0 * x1.powi(3) + 500.0 * x1.powi(2) + 4.0 * x1 + 20.0;
+        let db = 6900.0 * x1.powi(2) + 3800.0 * x1 + 2092.0;
+        let dc = 300.0 * x1.powi(2) + 1000.0 * x1 + 4.0;
+        [
+            a * (db * c - b * dc) / c.powi(2),
+            (-1.0 / 2.0 * x2).exp() / 2.0 * (b / c),
+        ]
+    }
+
     fn f1(&self, xs: &[f64]) -> f64 {
         let x1 = xs[0];
         let x2 = xs[1];
@@ -86,6 +126,22 @@ impl CurrinExponential {
         let d = self.f2(&[x1 - 0.05, 0f64.max(x2 - 0.05)]) / 4.0;
         a + b + c + d
     }
+
+    fn f1_gradient(&self, xs: &[f64]) -> [f64; 2] {
+        let x1 = xs[0];
+        let x2 = xs[1];
+
+        let lower_x2 = 0f64.max(x2 - 0.05);
+        let lower_dx2 = if x2 - 0.05 > 0.0 { 1.0 } else { 0.0 };
+        let a = self.f2_gradient(&[x1 + 0.05, x2 + 0.05]);
+        let b = self.f2_gradient(&[x1 + 0.05, lower_x2]);
+        let c = self.f2_gradient(&[x1 - 0.05, x2 + 0.05]);
+        let d = self.f2_gradient(&[x1 - 0.05, lower_x2]);
+        [
+            (a[0] + b[0] + c[0] + d[0]) / 4.0,
+            (a[1] + c[1] + (b[1] + d[1]) * lower_dx2) / 4.0,
+        ]
+    }
 }
 impl Objective for CurrinExponential {
     type Output = Outputs;
@@ -100,12 +156,32 @@ impl Objective for CurrinExponential {
             iter::once((ONE, self.f1(xs))).chain(iter::once((self.cost_factor, self.f2(xs)))),
         )
     }
+
+    fn sense(&self) -> Sense {
+        Sense::Maximize
+    }
 }
 impl MultiFidelitySingleObjective for CurrinExponential {
     fn max_cost(&self) -> Cost {
         self.cost_factor
     }
 }
+impl MultiFidelityDifferentiable for CurrinExponential {
+    fn gradients(&self, xs: &[f64]) -> Vec<(Cost, Vec<f64>)> {
+        vec![
+            (ONE, self.f1_gradient(xs).to_vec()),
+            (self.cost_factor, self.f2_gradient(xs).to_vec()),
+        ]
+    }
+}
+impl GlobalOptimum for CurrinExponential {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            5.429374059748039,
+            vec![vec![0.21666666666666667, 1.0]],
+        ))
+    }
+}
 
 /// Park function (2 fidelity).
 ///
@@ -136,6 +212,23 @@ impl Park {
         a * b + c
     }
 
+    fn f2_gradient(&self, xs: &[f64]) -> [f64; 4] {
+        let x1 = xs[0];
+        let x2 = xs[1];
+        let x3 = xs[2];
+        let x4 = xs[3];
+
+        let q = (x2 + x3.powi(2)) * (x4 / x1.powi(2));
+        let s = (1.0 + q).sqrt();
+        let e = (1.0 + x3.sin()).exp();
+        [
+            (s - 1.0) / 2.0 - q / (2.0 * s) + e,
+            x4 / (4.0 * s * x1),
+            x3 * x4 / (2.0 * s * x1) + (x1 + 3.0 * x4) * e * x3.cos(),
+            (x2 + x3.powi(2)) / (4.0 * s * x1) + 3.0 * e,
+        ]
+    }
+
     fn f1(&self, xs: &[f64]) -> f64 {
         let x1 = xs[0];
         let x2 = xs[1];
@@ -145,6 +238,21 @@ impl Park {
         let b = 2.0 * x1.powi(2) + x2.powi(2) + x3.powi(2) + 0.5;
         a - b
     }
+
+    fn f1_gradient(&self, xs: &[f64]) -> [f64; 4] {
+        let x1 = xs[0];
+        let x2 = xs[1];
+        let x3 = xs[2];
+
+        let k = 1.0 + x1.sin() / 10.0;
+        let g = self.f2_gradient(xs);
+        [
+            x1.cos() / 10.0 * self.f2(xs) + k * g[0] - 4.0 * x1,
+            k * g[1] - 2.0 * x2,
+            k * g[2] - 2.0 * x3,
+            k * g[3],
+        ]
+    }
 }
 impl Objective for Park {
     type Output = Outputs;
@@ -159,12 +267,32 @@ impl Objective for Park {
             iter::once((TEN, self.f1(xs))).chain(iter::once((self.cost_factor, self.f2(xs)))),
         )
     }
+
+    fn sense(&self) -> Sense {
+        Sense::Maximize
+    }
 }
 impl MultiFidelitySingleObjective for Park {
     fn max_cost(&self) -> Cost {
         self.cost_factor
     }
 }
+impl MultiFidelityDifferentiable for Park {
+    fn gradients(&self, xs: &[f64]) -> Vec<(Cost, Vec<f64>)> {
+        vec![
+            (TEN, self.f1_gradient(xs).to_vec()),
+            (self.cost_factor, self.f2_gradient(xs).to_vec()),
+        ]
+    }
+}
+impl GlobalOptimum for Park {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            25.589254158606548,
+            vec![vec![1.0, 1.0, 1.0, 1.0]],
+        ))
+    }
+}
 
 /// Borehole function (2 fidelity).
 ///
@@ -214,6 +342,42 @@ impl Borehole {
         let c = 1.5 + (2.0 * x7 * x3) / (b * x1.powi(2) * x8) + x3 / x5;
         a / (b * c)
     }
+
+    /// Gradient of `ka * x3 * (x4 - x6) / (b * (kc + (2 * x7 * x3) / (b * x1^2 * x8) + x3 / x5))`
+    /// where `b = ln(x2 / x1)`.
+    fn gradient(&self, ka: f64, kc: f64, xs: &[f64]) -> [f64; 8] {
+        let x1 = xs[0];
+        let x2 = xs[1];
+        let x3 = xs[2];
+        let x4 = xs[3];
+        let x5 = xs[4];
+        let x6 = xs[5];
+        let x7 = xs[6];
+        let x8 = xs[7];
+
+        let a = ka * x3 * (x4 - x6);
+        let b = (x2 / x1).ln();
+        let e = kc + x3 / x5;
+        let t = 2.0 * x7 * x3 / (x1.powi(2) * x8);
+        let d = b * e + t;
+
+        let da = [0.0, 0.0, ka * (x4 - x6), ka * x3, 0.0, -ka * x3, 0.0, 0.0];
+        let dd = [
+            -e / x1 - 2.0 * t / x1,
+            e / x2,
+            b / x5 + t / x3,
+            0.0,
+            -b * x3 / x5.powi(2),
+            0.0,
+            t / x7,
+            -t / x8,
+        ];
+        let mut g = [0.0; 8];
+        for i in 0..8 {
+            g[i] = da[i] / d - a * dd[i] / d.powi(2);
+        }
+        g
+    }
 }
 impl Objective for Borehole {
     type Output = Outputs;
@@ -239,12 +403,34 @@ impl Objective for Borehole {
             iter::once((ONE, self.f1(xs))).chain(iter::once((self.cost_factor, self.f2(xs)))),
         )
     }
+
+    fn sense(&self) -> Sense {
+        Sense::Maximize
+    }
 }
 impl MultiFidelitySingleObjective for Borehole {
     fn max_cost(&self) -> Cost {
         self.cost_factor
     }
 }
+impl MultiFidelityDifferentiable for Borehole {
+    fn gradients(&self, xs: &[f64]) -> Vec<(Cost, Vec<f64>)> {
+        vec![
+            (ONE, self.gradient(5.0, 1.5, xs).to_vec()),
+            (self.cost_factor, self.gradient(2.0 * PI, 1.0, xs).to_vec()),
+        ]
+    }
+}
+impl GlobalOptimum for Borehole {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            309.57558766040796,
+            vec![vec![
+                0.15, 100.0, 115_600.0, 1_110.0, 116.0, 700.0, 1_120.0, 12_045.0,
+            ]],
+        ))
+    }
+}
 
 /// Hartmann-3D function (multi fidelity).
 ///
@@ -260,6 +446,19 @@ impl Default for Hartmann3d {
     }
 }
 impl Hartmann3d {
+    const A: [[f64; 3]; 4] = [
+        [3.0, 10.0, 30.0],
+        [0.1, 10.0, 35.0],
+        [3.0, 10.0, 30.0],
+        [0.1, 10.0, 35.0],
+    ];
+    const P: [[f64; 3]; 4] = [
+        [0.3689, 0.1170, 0.2673],
+        [0.4699, 0.4387, 0.7470],
+        [0.1091, 0.8732, 0.5547],
+        [0.0381, 0.5743, 0.8828],
+    ];
+
     pub const fn new(max_level: u8, cost_factor: NonZeroU64) -> Self {
         Self {
             max_level,
@@ -275,29 +474,32 @@ impl Hartmann3d {
     }
 
     fn f(&self, m: u8, xs: &[f64]) -> f64 {
-        const A: [[f64; 3]; 4] = [
-            [3.0, 10.0, 30.0],
-            [0.1, 10.0, 35.0],
-            [3.0, 10.0, 30.0],
-            [0.1, 10.0, 35.0],
-        ];
-        const P: [[f64; 3]; 4] = [
-            [0.3689, 0.1170, 0.2673],
-            [0.4699, 0.4387, 0.7470],
-            [0.1091, 0.8732, 0.5547],
-            [0.0381, 0.5743, 0.8828],
-        ];
-
         (0..4)
             .map(|i| {
                 let a = self.alpha(m, i);
                 let b = (0..3)
-                    .map(|j| A[i][j] * (xs[j] - P[i][j]).powi(2))
+                    .map(|j| Self::A[i][j] * (xs[j] - Self::P[i][j]).powi(2))
                     .sum::<f64>();
                 a * (-b).exp()
             })
             .sum::<f64>()
     }
+
+    fn gradient(&self, m: u8, xs: &[f64]) -> Vec<f64> {
+        (0..3)
+            .map(|j| {
+                (0..4)
+                    .map(|i| {
+                        let a = self.alpha(m, i);
+                        let b = (0..3)
+                            .map(|k| Self::A[i][k] * (xs[k] - Self::P[i][k]).powi(2))
+                            .sum::<f64>();
+                        -2.0 * a * (-b).exp() * Self::A[i][j] * (xs[j] - Self::P[i][j])
+                    })
+                    .sum::<f64>()
+            })
+            .collect()
+    }
 }
 impl Objective for Hartmann3d {
     type Output = Outputs;
@@ -316,12 +518,39 @@ impl Objective for Hartmann3d {
             (c, v)
         }))
     }
+
+    fn sense(&self) -> Sense {
+        Sense::Maximize
+    }
 }
 impl MultiFidelitySingleObjective for Hartmann3d {
     fn max_cost(&self) -> Cost {
         unsafe { NonZeroU64::new_unchecked(self.cost_factor.get().pow(u32::from(self.max_level))) }
     }
 }
+impl MultiFidelityDifferentiable for Hartmann3d {
+    fn gradients(&self, xs: &[f64]) -> Vec<(Cost, Vec<f64>)> {
+        (0..=self.max_level)
+            .map(|m| {
+                let c =
+                    unsafe { NonZeroU64::new_unchecked(self.cost_factor.get().pow(u32::from(m))) };
+                (c, self.gradient(m, xs))
+            })
+            .collect()
+    }
+}
+impl GlobalOptimum for Hartmann3d {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            3.8627797873326624,
+            vec![vec![
+                0.11458887665506898,
+                0.55564889461693,
+                0.8525469846866774,
+            ]],
+        ))
+    }
+}
 
 /// Hartmann-6D function (multi fidelity).
 ///
@@ -337,6 +566,19 @@ impl Default for Hartmann6d {
     }
 }
 impl Hartmann6d {
+    const A: [[f64; 6]; 4] = [
+        [10.0, 3.0, 17.0, 3.5, 1.7, 8.0],
+        [0.05, 10.0, 17.0, 0.1, 8.0, 14.0],
+        [3.0, 3.5, 1.7, 10.0, 17.0, 8.0],
+        [17.0, 8.0, 0.05, 10.0, 0.1, 14.0],
+    ];
+    const P: [[f64; 6]; 4] = [
+        [0.1312, 0.1696, 0.5569, 0.0124, 0.8283, 0.5886],
+        [0.2329, 0.4135, 0.8307, 0.3736, 0.1004, 0.9991],
+        [0.2348, 0.1451, 0.3522, 0.2883, 0.3047, 0.6650],
+        [0.4047, 0.8828, 0.8732, 0.5743, 0.1091, 0.0381],
+    ];
+
     pub const fn new(max_level: u8, cost_factor: NonZeroU64) -> Self {
         Self {
             max_level,
@@ -352,29 +594,32 @@ impl Hartmann6d {
     }
 
     fn f(&self, m: u8, xs: &[f64]) -> f64 {
-        const A: [[f64; 6]; 4] = [
-            [10.0, 3.0, 17.0, 3.5, 1.7, 8.0],
-            [0.05, 10.0, 17.0, 0.1, 8.0, 14.0],
-            [3.0, 3.5, 1.7, 10.0, 17.0, 8.0],
-            [17.0, 8.0, 0.05, 10.0, 0.1, 14.0],
-        ];
-        const P: [[f64; 6]; 4] = [
-            [0.1312, 0.1696, 0.5569, 0.0124, 0.8283, 0.5886],
-            [0.2329, 0.4135, 0.8307, 0.3736, 0.1004, 0.9991],
-            [0.2348, 0.1451, 0.3522, 0.2883, 0.3047, 0.6650],
-            [0.4047, 0.8828, 0.8732, 0.5743, 0.1091, 0.0381],
-        ];
-
         (0..4)
             .map(|i| {
                 let a = self.alpha(m, i);
                 let b = (0..6)
-                    .map(|j| A[i][j] * (xs[j] - P[i][j]).powi(2))
+                    .map(|j| Self::A[i][j] * (xs[j] - Self::P[i][j]).powi(2))
                     .sum::<f64>();
                 a * (-b).exp()
             })
             .sum::<f64>()
     }
+
+    fn gradient(&self, m: u8, xs: &[f64]) -> Vec<f64> {
+        (0..6)
+            .map(|j| {
+                (0..4)
+                    .map(|i| {
+                        let a = self.alpha(m, i);
+                        let b = (0..6)
+                            .map(|k| Self::A[i][k] * (xs[k] - Self::P[i][k]).powi(2))
+                            .sum::<f64>();
+                        -2.0 * a * (-b).exp() * Self::A[i][j] * (xs[j] - Self::P[i][j])
+                    })
+                    .sum::<f64>()
+            })
+            .collect()
+    }
 }
 impl Objective for Hartmann6d {
     type Output = Outputs;
@@ -400,9 +645,81 @@ impl Objective for Hartmann6d {
             (c, v)
         }))
     }
+
+    fn sense(&self) -> Sense {
+        Sense::Maximize
+    }
 }
 impl MultiFidelitySingleObjective for Hartmann6d {
     fn max_cost(&self) -> Cost {
         unsafe { NonZeroU64::new_unchecked(self.cost_factor.get().pow(u32::from(self.max_level))) }
     }
 }
+impl MultiFidelityDifferentiable for Hartmann6d {
+    fn gradients(&self, xs: &[f64]) -> Vec<(Cost, Vec<f64>)> {
+        (0..=self.max_level)
+            .map(|m| {
+                let c =
+                    unsafe { NonZeroU64::new_unchecked(self.cost_factor.get().pow(u32::from(m))) };
+                (c, self.gradient(m, xs))
+            })
+            .collect()
+    }
+}
+impl GlobalOptimum for Hartmann6d {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            3.3223680114155147,
+            vec![vec![
+                0.20168951100670543,
+                0.15001069182345797,
+                0.47687397422189703,
+                0.2753324304940561,
+                0.3116516166001133,
+                0.6573005340656204,
+            ]],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_multi_fidelity_global_optimum, assert_multi_fidelity_gradients};
+
+    #[test]
+    fn gradients_work() {
+        assert_multi_fidelity_gradients(&CurrinExponential::default(), &[0.3, 0.6]);
+        assert_multi_fidelity_gradients(&CurrinExponential::default(), &[0.7, 0.01]);
+        assert_multi_fidelity_gradients(&Park::default(), &[0.3, 0.6, 0.2, 0.9]);
+        assert_multi_fidelity_gradients(
+            &Borehole::default(),
+            &[
+                0.1, 2_000.0, 80_000.0, 1_000.0, 90.0, 750.0, 1_500.0, 10_000.0,
+            ],
+        );
+        assert_multi_fidelity_gradients(&Hartmann3d::default(), &[0.1, 0.5, 0.8]);
+        assert_multi_fidelity_gradients(&Hartmann6d::default(), &[0.2, 0.1, 0.4, 0.3, 0.3, 0.6]);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        assert_multi_fidelity_global_optimum(&CurrinExponential::default());
+        assert_multi_fidelity_global_optimum(&Park::default());
+        assert_multi_fidelity_global_optimum(&Borehole::default());
+        assert_multi_fidelity_global_optimum(&Hartmann3d::default());
+        assert_multi_fidelity_global_optimum(&Hartmann6d::default());
+    }
+
+    #[test]
+    fn evaluate_batch_works() {
+        let f = Park::default();
+        let xs = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8];
+        let mut ys = Vec::new();
+        f.evaluate_batch(&xs, &mut ys);
+        assert_eq!(ys.len(), 2);
+        for (xs, ys) in xs.chunks(4).zip(ys) {
+            assert_eq!(f.evaluate(xs).collect::<Vec<_>>(), ys.collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! Wrapper that adds random noise to the outputs of objectives.
+use crate::{
+    Error, GlobalOptimum, Interval, Objective, Optimum, Sense, SingleObjective,
+    StochasticObjective, VariableType,
+};
+use rand::distributions::{Cauchy, Distribution, Normal, StudentT, Uniform};
+use rand::rngs::StdRng;
+use rand::{FromEntropy, Rng, SeedableRng};
+use std::fmt;
+use std::sync::Mutex;
+
+/// Distribution of the standardized noise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NoiseDistribution {
+    /// Standard normal distribution.
+    Gaussian,
+
+    /// Standard Cauchy distribution.
+    Cauchy,
+
+    /// Uniform distribution over `[-1, 1)`.
+    Uniform,
+
+    /// Student's t-distribution with the given degrees of freedom.
+    StudentT { degrees_of_freedom: f64 },
+}
+impl NoiseDistribution {
+    fn sample<R>(&self, rng: &mut R) -> f64
+    where
+        R: Rng + ?Sized,
+    {
+        match *self {
+            NoiseDistribution::Gaussian => Normal::new(0.0, 1.0).sample(rng),
+            NoiseDistribution::Cauchy => Cauchy::new(0.0, 1.0).sample(rng),
+            NoiseDistribution::Uniform => Uniform::new(-1.0, 1.0).sample(rng),
+            NoiseDistribution::StudentT { degrees_of_freedom } => {
+                StudentT::new(degrees_of_freedom).sample(rng)
+            }
+        }
+    }
+}
+
+/// How noise is applied to outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoiseKind {
+    /// `y + scale * e`.
+    Additive,
+
+    /// `y * (1 + scale * e)`.
+    Multiplicative,
+}
+
+type ScaleFn = Box<dyn Fn(&[f64]) -> f64 + Send + Sync>;
+
+enum Scale {
+    Constant(f64),
+    Function(ScaleFn),
+}
+impl Scale {
+    fn get(&self, xs: &[f64]) -> f64 {
+        match self {
+            Scale::Constant(s) => *s,
+            Scale::Function(f) => f(xs),
+        }
+    }
+}
+impl fmt::Debug for Scale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Scale::Constant(s) => write!(f, "Constant({})", s),
+            Scale::Function(_) => write!(f, "Function(_)"),
+        }
+    }
+}
+
+/// Wrapper that adds random noise to the outputs of a single objective.
+///
+/// The random numbers are drawn from an internal RNG, which is seeded from entropy by default (see `with_seed`).
+/// `evaluate_with_rng` can be used to supply an RNG per evaluation instead.
+#[derive(Debug)]
+pub struct Noisy<T> {
+    inner: T,
+    distribution: NoiseDistribution,
+    kind: NoiseKind,
+    scale: Scale,
+    rng: Mutex<StdRng>,
+}
+impl<T> Noisy<T>
+where
+    T: SingleObjective,
+{
+    /// Makes a new `Noisy` instance that adds `scale * e` to outputs, where `e` is drawn from `distribution`.
+    pub fn new(inner: T, distribution: NoiseDistribution, scale: f64) -> Self {
+        Self {
+            inner,
+            distribution,
+            kind: NoiseKind::Additive,
+            scale: Scale::Constant(scale),
+            rng: Mutex::new(StdRng::from_entropy()),
+        }
+    }
+
+    /// Sets how noise is applied to outputs.
+    pub fn with_kind(mut self, kind: NoiseKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Makes the noise heteroscedastic, i.e., the scale is determined by `f(xs)`.
+    pub fn with_scale_fn<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(&[f64]) -> f64 + Send + Sync,
+    {
+        self.scale = Scale::Function(Box::new(f));
+        self
+    }
+
+    /// Reseeds the internal RNG of this instance with `seed`.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+        self
+    }
+
+    /// Evaluates `xs` by using `rng` as the source of randomness instead of the internal RNG.
+    pub fn evaluate_with_rng<R>(&self, xs: &[f64], rng: &mut R) -> f64
+    where
+        R: Rng + ?Sized,
+    {
+        let y = self.inner.evaluate(xs);
+        let e = self.scale.get(xs) * self.distribution.sample(rng);
+        match self.kind {
+            NoiseKind::Additive => y + e,
+            NoiseKind::Multiplicative => y * (1.0 + e),
+        }
+    }
+
+    /// Returns a reference to the wrapped objective.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the wrapped objective.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+impl<T> Objective for Noisy<T>
+where
+    T: SingleObjective,
+{
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        self.inner.input_domain()
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        let mut rng = self.rng.lock().unwrap_or_else(|e| e.into_inner());
+        self.evaluate_with_rng(xs, &mut *rng)
+    }
+
+    fn sense(&self) -> Sense {
+        self.inner.sense()
+    }
+
+    fn variable_types(&self) -> Vec<VariableType> {
+        self.inner.variable_types()
+    }
+
+    fn validate(&self, xs: &[f64]) -> Result<(), Error> {
+        self.inner.validate(xs)
+    }
+}
+impl<T> StochasticObjective for Noisy<T>
+where
+    T: SingleObjective,
+{
+    fn evaluate_with_seed(&self, xs: &[f64], seed: u64) -> f64 {
+        self.evaluate_with_rng(xs, &mut StdRng::seed_from_u64(seed))
+    }
+}
+impl<T> GlobalOptimum for Noisy<T>
+where
+    T: SingleObjective + GlobalOptimum,
+{
+    /// Returns the global optimum of the noiseless objective.
+    fn global_optimum(&self) -> Option<Optimum> {
+        self.inner.global_optimum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Adjiman;
+
+    #[test]
+    fn noisy_works() {
+        let distributions = [
+            NoiseDistribution::Gaussian,
+            NoiseDistribution::Cauchy,
+            NoiseDistribution::Uniform,
+            NoiseDistribution::StudentT {
+                degrees_of_freedom: 3.0,
+            },
+        ];
+        let xs = [1.0, 0.5];
+        for &d in &distributions {
+            let f0 = Noisy::new(Adjiman, d, 0.1).with_seed(1);
+            let f1 = Noisy::new(Adjiman, d, 0.1).with_seed(1);
+            let ys0 = (0..10).map(|_| f0.evaluate(&xs)).collect::<Vec<_>>();
+            let ys1 = (0..10).map(|_| f1.evaluate(&xs)).collect::<Vec<_>>();
+            assert_eq!(ys0, ys1);
+            assert!(ys0.iter().any(|&y| y != Adjiman.evaluate(&xs)));
+        }
+
+        let f = Noisy::new(Adjiman, NoiseDistribution::Uniform, 0.1);
+        let y = Adjiman.evaluate(&xs);
+        for _ in 0..100 {
+            assert!((f.evaluate(&xs) - y).abs() <= 0.1);
+        }
+    }
+
+    #[test]
+    fn common_random_numbers_work() {
+        let f = Noisy::new(Adjiman, NoiseDistribution::Gaussian, 1.0);
+        let xs0 = [1.0, 0.5];
+        let xs1 = [-0.5, 0.2];
+        for seed in 0..10 {
+            let d = f.evaluate_with_seed(&xs0, seed) - f.evaluate_with_seed(&xs1, seed);
+            let expected = Adjiman.evaluate(&xs0) - Adjiman.evaluate(&xs1);
+            assert!((d - expected).abs() < 1e-12);
+        }
+        assert_ne!(f.evaluate_with_seed(&xs0, 0), f.evaluate_with_seed(&xs0, 1));
+    }
+
+    #[test]
+    fn heteroscedastic_noise_works() {
+        let f = Noisy::new(Adjiman, NoiseDistribution::Gaussian, 0.0)
+            .with_kind(NoiseKind::Multiplicative)
+            .with_scale_fn(|xs| if xs[0] < 0.0 { 0.0 } else { 1.0 });
+        assert_eq!(f.evaluate(&[-0.5, 0.5]), Adjiman.evaluate(&[-0.5, 0.5]));
+        assert_ne!(f.evaluate(&[0.5, 0.5]), Adjiman.evaluate(&[0.5, 0.5]));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{interval, Differentiable, GlobalOptimum, Interval, Objective, Optimum};
+use std::num::NonZeroUsize;
+
+/// Perm 0, d, β Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Perm Function 0, d, β](https://www.sfu.ca/~ssurjano/perm0db.html)
+#[derive(Debug, Clone)]
+pub struct Perm0DBeta {
+    input_domain: Vec<Interval>,
+    beta: f64,
+}
+impl Perm0DBeta {
+    /// Makes a new `Perm0DBeta` instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `beta` is not positive.
+    pub fn new(dimension: NonZeroUsize, beta: f64) -> Self {
+        assert!(beta > 0.0);
+        let d = dimension.get() as f64;
+        let input_domain = (0..dimension.get()).map(|_| interval(-d, d)).collect();
+        Self { input_domain, beta }
+    }
+
+    fn inner_sums(&self, xs: &[f64]) -> Vec<f64> {
+        (1..=xs.len() as i32)
+            .map(|i| {
+                xs.iter()
+                    .enumerate()
+                    .map(|(j, &x)| {
+                        let j = (j + 1) as f64;
+                        (j + self.beta) * (x.powi(i) - j.powi(-i))
+                    })
+                    .sum::<f64>()
+            })
+            .collect()
+    }
+}
+impl Objective for Perm0DBeta {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        self.inner_sums(xs).into_iter().map(|s| s * s).sum()
+    }
+}
+impl GlobalOptimum for Perm0DBeta {
+    fn global_optimum(&self) -> Option<Optimum> {
+        let xs = (1..=self.dimension().get())
+            .map(|j| 1.0 / j as f64)
+            .collect();
+        Some(Optimum::new(0.0, vec![xs]))
+    }
+}
+impl Differentiable for Perm0DBeta {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let sums = self.inner_sums(xs);
+        xs.iter()
+            .enumerate()
+            .map(|(j, &x)| {
+                let k = (j + 1) as f64 + self.beta;
+                sums.iter()
+                    .enumerate()
+                    .map(|(i, s)| 2.0 * s * k * (i + 1) as f64 * x.powi(i as i32))
+                    .sum::<f64>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_global_optimum, assert_gradient, assert_reference_values};
+
+    #[test]
+    fn perm0dbeta_works() {
+        let xs = [
+            [
+                0.042160895673958976,
+                -0.41285067941440357,
+                1.3218675128648307,
+            ],
+            [-1.5741862832112956, -1.1934788329551036, 2.8667838986918124],
+            [0.1267637596872362, 0.29058280612117304, -2.931255081814686],
+            [-0.5087379371767078, 0.4797912827823936, -2.879682658172404],
+            [0.6947876478375408, 0.7930832117766924, -2.6395169362336617],
+        ];
+        let ys = [
+            424.2893583814157,
+            71571.58872861718,
+            128129.1553251114,
+            117713.49579113036,
+            67966.59366080682,
+        ];
+
+        let f = Perm0DBeta::new(NonZeroUsize::new(3).unwrap(), 10.0);
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        assert_global_optimum(&Perm0DBeta::new(NonZeroUsize::new(5).unwrap(), 10.0));
+    }
+
+    #[test]
+    fn gradients_work() {
+        let f = Perm0DBeta::new(NonZeroUsize::new(3).unwrap(), 10.0);
+        assert_gradient(&f, &[0.3, -1.2, 2.5]);
+    }
+}
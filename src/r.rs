@@ -0,0 +1,245 @@
+use crate::{interval, Differentiable, GlobalOptimum, Interval, Objective, Optimum};
+use std::f64::consts::PI;
+use std::num::NonZeroUsize;
+
+/// Rastrigin Function.
+///
+/// # References
+///
+/// - [BenchmarkFcns: Rastrigin Function](http://benchmarkfcns.xyz/benchmarkfcns/rastriginfcn.html)
+#[derive(Debug, Clone)]
+pub struct Rastrigin {
+    input_domain: Vec<Interval>,
+}
+impl Rastrigin {
+    /// Makes a new `Rastrigin` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get())
+            .map(|_| interval(-5.12, 5.12))
+            .collect();
+        Self { input_domain }
+    }
+}
+impl Objective for Rastrigin {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        10.0 * xs.len() as f64
+            + xs.iter()
+                .map(|&x| x * x - 10.0 * (2.0 * PI * x).cos())
+                .sum::<f64>()
+    }
+}
+impl GlobalOptimum for Rastrigin {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0; self.dimension().get()]]))
+    }
+}
+impl Differentiable for Rastrigin {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        xs.iter()
+            .map(|&x| 2.0 * x + 20.0 * PI * (2.0 * PI * x).sin())
+            .collect()
+    }
+}
+
+/// Rosenbrock Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Rosenbrock Function](https://www.sfu.ca/~ssurjano/rosen.html)
+#[derive(Debug, Clone)]
+pub struct Rosenbrock {
+    input_domain: Vec<Interval>,
+}
+impl Rosenbrock {
+    /// Makes a new `Rosenbrock` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get()).map(|_| interval(-5.0, 10.0)).collect();
+        Self { input_domain }
+    }
+}
+impl Objective for Rosenbrock {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        xs.windows(2)
+            .map(|w| 100.0 * (w[1] - w[0] * w[0]).powi(2) + (w[0] - 1.0).powi(2))
+            .sum()
+    }
+}
+impl GlobalOptimum for Rosenbrock {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![1.0; self.dimension().get()]]))
+    }
+}
+impl Differentiable for Rosenbrock {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let n = xs.len();
+        (0..n)
+            .map(|i| {
+                let mut g = 0.0;
+                if i + 1 < n {
+                    g += -400.0 * xs[i] * (xs[i + 1] - xs[i] * xs[i]) + 2.0 * (xs[i] - 1.0);
+                }
+                if i > 0 {
+                    g += 200.0 * (xs[i] - xs[i - 1] * xs[i - 1]);
+                }
+                g
+            })
+            .collect()
+    }
+}
+
+/// Rotated Hyper-Ellipsoid Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Rotated Hyper-Ellipsoid Function](https://www.sfu.ca/~ssurjano/rothyp.html)
+#[derive(Debug, Clone)]
+pub struct RotatedHyperEllipsoid {
+    input_domain: Vec<Interval>,
+}
+impl RotatedHyperEllipsoid {
+    /// Makes a new `RotatedHyperEllipsoid` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get())
+            .map(|_| interval(-65.536, 65.536))
+            .collect();
+        Self { input_domain }
+    }
+}
+impl Objective for RotatedHyperEllipsoid {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let n = xs.len();
+        xs.iter()
+            .enumerate()
+            .map(|(i, &x)| (n - i) as f64 * x * x)
+            .sum()
+    }
+}
+impl GlobalOptimum for RotatedHyperEllipsoid {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0; self.dimension().get()]]))
+    }
+}
+impl Differentiable for RotatedHyperEllipsoid {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let n = xs.len();
+        xs.iter()
+            .enumerate()
+            .map(|(i, &x)| 2.0 * (n - i) as f64 * x)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_global_optimum, assert_gradient, assert_reference_values};
+
+    #[test]
+    fn rastrigin_works() {
+        let xs = [
+            [4.669790944145913, 4.585753467487737, -4.540913994477479],
+            [-4.250910769572642, 3.435508512045563, 2.4163326880616784],
+            [1.7380393107478618, -1.9646826742871633, 1.0848682565474554],
+            [1.0936497524821798, 0.8315291352269121, -3.4981594085907908],
+            [-0.7099428834174093, -1.0902341610969977, 2.28364371187165],
+        ];
+        let ys = [
+            116.5396406287054,
+            83.60878109017939,
+            20.442073018715117,
+            40.904259918303225,
+            33.060834554908006,
+        ];
+
+        let f = Rastrigin::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        let d = NonZeroUsize::new(5).unwrap();
+        assert_global_optimum(&Rastrigin::new(d));
+        assert_global_optimum(&Rosenbrock::new(d));
+        assert_global_optimum(&RotatedHyperEllipsoid::new(d));
+    }
+
+    #[test]
+    fn gradients_work() {
+        assert_gradient(
+            &Rastrigin::new(NonZeroUsize::new(3).unwrap()),
+            &[0.3, -1.2, 2.5],
+        );
+    }
+
+    #[test]
+    fn rosenbrock_works() {
+        let xs = [
+            [0.787993882673538, 0.2636573315527011, 3.7761116110804522],
+            [3.763776894552983, 8.563026562716626, 5.2297320495245],
+            [8.934184018000256, 7.8460084959513345, 9.864844673032227],
+            [5.069103132437773, -2.5535056704339536, 7.9095629967440235],
+            [9.46949420963592, 8.570439767683549, 3.5366125521148515],
+        ];
+        let ys = [
+            1387.2375385650143,
+            466906.51251796214,
+            785367.5913767533,
+            80024.52901890027,
+            1146686.593016415,
+        ];
+
+        let f = Rosenbrock::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn rotated_hyper_ellipsoid_works() {
+        let xs = [
+            [28.066379675887546, 55.1942445575536, -13.76735671081449],
+            [39.44071443057584, -7.258628941314704, 57.09322278725499],
+            [49.658810903827785, -52.762468714954466, -47.714289555777924],
+            [-37.095087638690224, 61.011412765665256, -8.36739181740959],
+            [16.600044780493732, -26.07989411997216, 0.9493523764424907],
+        ];
+        let ys = [
+            8645.514379694101,
+            8031.7213408332145,
+            15242.402138752092,
+            11642.934801917141,
+            2187.9074846957656,
+        ];
+
+        let f = RotatedHyperEllipsoid::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+}
@@ -0,0 +1,662 @@
+use crate::{interval, Differentiable, GlobalOptimum, Interval, Objective, Optimum};
+use std::f64::consts::PI;
+use std::num::NonZeroUsize;
+
+/// Salomon Function.
+///
+/// # References
+///
+/// - [BenchmarkFcns: Salomon Function](http://benchmarkfcns.xyz/benchmarkfcns/salomonfcn.html)
+#[derive(Debug, Clone)]
+pub struct Salomon {
+    input_domain: Vec<Interval>,
+}
+impl Salomon {
+    /// Makes a new `Salomon` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get())
+            .map(|_| interval(-100.0, 100.0))
+            .collect();
+        Self { input_domain }
+    }
+}
+impl Objective for Salomon {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let r = xs.iter().map(|&x| x * x).sum::<f64>().sqrt();
+        1.0 - (2.0 * PI * r).cos() + 0.1 * r
+    }
+}
+impl GlobalOptimum for Salomon {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0; self.dimension().get()]]))
+    }
+}
+impl Differentiable for Salomon {
+    /// Returns the gradient at `xs`.
+    ///
+    /// The function is not differentiable at the origin, where the zero vector is returned.
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let r = xs.iter().map(|&x| x * x).sum::<f64>().sqrt();
+        if r == 0.0 {
+            return vec![0.0; xs.len()];
+        }
+        let k = (2.0 * PI * (2.0 * PI * r).sin() + 0.1) / r;
+        xs.iter().map(|&x| k * x).collect()
+    }
+}
+
+/// Schaffer Function N. 2.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Schaffer Function N. 2](https://www.sfu.ca/~ssurjano/schaffer2.html)
+#[derive(Debug, Clone)]
+pub struct SchafferN2;
+impl Objective for SchafferN2 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-100.0, 100.0);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let d = 1.0 + 0.001 * (x.powi(2) + y.powi(2));
+        0.5 + ((x.powi(2) - y.powi(2)).sin().powi(2) - 0.5) / d.powi(2)
+    }
+}
+impl GlobalOptimum for SchafferN2 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0, 0.0]]))
+    }
+}
+impl Differentiable for SchafferN2 {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let a = x.powi(2) - y.powi(2);
+        let d = 1.0 + 0.001 * (x.powi(2) + y.powi(2));
+        let n = a.sin().powi(2) - 0.5;
+        let s = (2.0 * a).sin();
+        vec![
+            2.0 * x * s / d.powi(2) - n * 0.004 * x / d.powi(3),
+            -2.0 * y * s / d.powi(2) - n * 0.004 * y / d.powi(3),
+        ]
+    }
+}
+
+/// Schaffer Function N. 4.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Schaffer Function N. 4](https://www.sfu.ca/~ssurjano/schaffer4.html)
+#[derive(Debug, Clone)]
+pub struct SchafferN4;
+impl Objective for SchafferN4 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-100.0, 100.0);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        let d = 1.0 + 0.001 * (x.powi(2) + y.powi(2));
+        0.5 + ((x.powi(2) - y.powi(2)).abs().sin().cos().powi(2) - 0.5) / d.powi(2)
+    }
+}
+impl GlobalOptimum for SchafferN4 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        const X: f64 = 1.2531318314637332;
+        Some(Optimum::new(
+            0.29257863203598056,
+            vec![vec![0.0, X], vec![0.0, -X], vec![X, 0.0], vec![-X, 0.0]],
+        ))
+    }
+}
+
+/// Schwefel 2.26 Function.
+///
+/// # References
+///
+/// - [A Literature Survey of Benchmark Functions For Global Optimization Problems](https://arxiv.org/abs/1308.4008)
+#[derive(Debug, Clone)]
+pub struct Schwefel226 {
+    input_domain: Vec<Interval>,
+}
+impl Schwefel226 {
+    /// Makes a new `Schwefel226` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get())
+            .map(|_| interval(-500.0, 500.0))
+            .collect();
+        Self { input_domain }
+    }
+}
+impl Objective for Schwefel226 {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        -xs.iter().map(|&x| x * x.abs().sqrt().sin()).sum::<f64>() / xs.len() as f64
+    }
+}
+impl GlobalOptimum for Schwefel226 {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(
+            -418.9828872724337,
+            vec![vec![420.96874635998205; self.dimension().get()]],
+        ))
+    }
+}
+impl Differentiable for Schwefel226 {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let n = xs.len() as f64;
+        xs.iter()
+            .map(|&x| {
+                let s = x.abs().sqrt();
+                -(s.sin() + 0.5 * s * s.cos()) / n
+            })
+            .collect()
+    }
+}
+
+/// Six-Hump Camel Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Six-Hump Camel Function](https://www.sfu.ca/~ssurjano/camel6.html)
+#[derive(Debug, Clone)]
+pub struct SixHumpCamel;
+impl Objective for SixHumpCamel {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-3.0, 3.0);
+        const Y: Interval = interval(-2.0, 2.0);
+        &[X, Y]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        (4.0 - 2.1 * x.powi(2) + x.powi(4) / 3.0) * x.powi(2)
+            + x * y
+            + (-4.0 + 4.0 * y.powi(2)) * y.powi(2)
+    }
+}
+impl GlobalOptimum for SixHumpCamel {
+    fn global_optimum(&self) -> Option<Optimum> {
+        const X: f64 = 0.08984201310031806;
+        const Y: f64 = 0.7126564030207396;
+        Some(Optimum::new(
+            -1.0316284534898774,
+            vec![vec![X, -Y], vec![-X, Y]],
+        ))
+    }
+}
+impl Differentiable for SixHumpCamel {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        vec![
+            8.0 * x - 8.4 * x.powi(3) + 2.0 * x.powi(5) + y,
+            x - 8.0 * y + 16.0 * y.powi(3),
+        ]
+    }
+}
+
+/// Sphere Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Sphere Function](https://www.sfu.ca/~ssurjano/spheref.html)
+#[derive(Debug, Clone)]
+pub struct Sphere {
+    input_domain: Vec<Interval>,
+}
+impl Sphere {
+    /// Makes a new `Sphere` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get())
+            .map(|_| interval(-5.12, 5.12))
+            .collect();
+        Self { input_domain }
+    }
+}
+impl Objective for Sphere {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        xs.iter().map(|&x| x * x).sum()
+    }
+}
+impl GlobalOptimum for Sphere {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0; self.dimension().get()]]))
+    }
+}
+impl Differentiable for Sphere {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        xs.iter().map(|&x| 2.0 * x).collect()
+    }
+}
+
+/// Styblinski-Tang Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Styblinski-Tang Function](https://www.sfu.ca/~ssurjano/stybtang.html)
+#[derive(Debug, Clone)]
+pub struct StyblinskiTang {
+    input_domain: Vec<Interval>,
+}
+impl StyblinskiTang {
+    /// Makes a new `StyblinskiTang` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get()).map(|_| interval(-5.0, 5.0)).collect();
+        Self { input_domain }
+    }
+}
+impl Objective for StyblinskiTang {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        xs.iter()
+            .map(|&x| x.powi(4) - 16.0 * x * x + 5.0 * x)
+            .sum::<f64>()
+            / 2.0
+    }
+}
+impl GlobalOptimum for StyblinskiTang {
+    fn global_optimum(&self) -> Option<Optimum> {
+        let d = self.dimension().get();
+        Some(Optimum::new(
+            -39.16616570377141 * d as f64,
+            vec![vec![-2.903534027771177; d]],
+        ))
+    }
+}
+impl Differentiable for StyblinskiTang {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        xs.iter()
+            .map(|&x| (4.0 * x.powi(3) - 32.0 * x + 5.0) / 2.0)
+            .collect()
+    }
+}
+
+/// Sum of Different Powers Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Sum of Different Powers Function](https://www.sfu.ca/~ssurjano/sumpow.html)
+#[derive(Debug, Clone)]
+pub struct SumOfDifferentPowers {
+    input_domain: Vec<Interval>,
+}
+impl SumOfDifferentPowers {
+    /// Makes a new `SumOfDifferentPowers` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get()).map(|_| interval(-1.0, 1.0)).collect();
+        Self { input_domain }
+    }
+}
+impl Objective for SumOfDifferentPowers {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        xs.iter()
+            .enumerate()
+            .map(|(i, &x)| x.abs().powi(i as i32 + 2))
+            .sum()
+    }
+}
+impl GlobalOptimum for SumOfDifferentPowers {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0; self.dimension().get()]]))
+    }
+}
+impl Differentiable for SumOfDifferentPowers {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        xs.iter()
+            .enumerate()
+            .map(|(i, &x)| (i + 2) as f64 * x.abs().powi(i as i32 + 1) * x.signum())
+            .collect()
+    }
+}
+
+/// Sum Squares Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Sum Squares Function](https://www.sfu.ca/~ssurjano/sumsqu.html)
+#[derive(Debug, Clone)]
+pub struct SumSquares {
+    input_domain: Vec<Interval>,
+}
+impl SumSquares {
+    /// Makes a new `SumSquares` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get())
+            .map(|_| interval(-10.0, 10.0))
+            .collect();
+        Self { input_domain }
+    }
+}
+impl Objective for SumSquares {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        xs.iter()
+            .enumerate()
+            .map(|(i, &x)| (i + 1) as f64 * x * x)
+            .sum()
+    }
+}
+impl GlobalOptimum for SumSquares {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0; self.dimension().get()]]))
+    }
+}
+impl Differentiable for SumSquares {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        xs.iter()
+            .enumerate()
+            .map(|(i, &x)| 2.0 * (i + 1) as f64 * x)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_global_optimum, assert_gradient, assert_reference_values};
+
+    #[test]
+    fn salomon_works() {
+        let xs = [
+            [-69.27787387799793, 87.09330716524394, -97.62530893024166],
+            [62.07101295373715, -87.98341620254617, -55.995257828873356],
+            [-43.99998976821673, -29.170293145262434, 70.55612543373698],
+            [-14.472906566747028, 62.27708607867689, -46.58566482343311],
+            [-52.12236800030381, -67.252130296541, 9.892028703752104],
+        ];
+        let ys = [
+            14.83281129933658,
+            13.795784024439817,
+            9.081473352020135,
+            8.133591014069818,
+            10.107679918868174,
+        ];
+
+        let f = Salomon::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn schwefel226_works() {
+        let xs = [
+            [-410.3427743766215, 94.0712211367711, 270.59565384068424],
+            [-40.978244932041264, 455.105791808198, -94.64947261599934],
+            [246.77430767710496, 29.90325801990184, -411.7312433155891],
+            [354.28673117064307, -122.01988643279327, 107.09171871937815],
+            [-437.68773237540756, 54.73012821750933, 84.29490190576985],
+        ];
+        let ys = [
+            204.39214903561486,
+            -100.5972408818767,
+            143.44197452439084,
+            -8.945613975594583,
+            104.83598338778822,
+        ];
+
+        let f = Schwefel226::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn styblinski_tang_works() {
+        let xs = [
+            [1.5029029874902324, 1.6427439722033927, 2.2224875862950277],
+            [0.17906464509384268, 3.340128103222783, 4.013567853609102],
+            [-2.483590288516604, -4.878111765867592, 1.5747451004446544],
+            [-0.2978955612737435, 1.0274262170814596, 4.819523018979112],
+            [1.620021365906127, 4.990346102933186, -0.09777634789824763],
+        ];
+        let ys = [
+            -47.36264090664318,
+            -7.566765084091817,
+            31.20290075627846,
+            89.22192999308648,
+            109.518280092272,
+        ];
+
+        let f = StyblinskiTang::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        let d = NonZeroUsize::new(5).unwrap();
+        assert_global_optimum(&Salomon::new(d));
+        assert_global_optimum(&Schwefel226::new(d));
+        assert_global_optimum(&Sphere::new(d));
+        assert_global_optimum(&StyblinskiTang::new(d));
+        assert_global_optimum(&SumOfDifferentPowers::new(d));
+        assert_global_optimum(&SumSquares::new(d));
+        assert_global_optimum(&SchafferN2);
+        assert_global_optimum(&SchafferN4);
+        assert_global_optimum(&SixHumpCamel);
+    }
+
+    #[test]
+    fn gradients_work() {
+        let d = NonZeroUsize::new(3).unwrap();
+        assert_gradient(&Salomon::new(d), &[0.3, -1.2, 2.5]);
+        assert_gradient(&Schwefel226::new(d), &[30.0, -120.0, 250.0]);
+        assert_gradient(&Sphere::new(d), &[0.3, -1.2, 2.5]);
+        assert_gradient(&StyblinskiTang::new(d), &[0.3, -1.2, 2.5]);
+        assert_gradient(&SumOfDifferentPowers::new(d), &[0.3, -0.2, 0.5]);
+        assert_gradient(&SumSquares::new(d), &[0.3, -1.2, 2.5]);
+        for xs in &[[0.3, -1.2], [1.7, 0.4], [-0.5, 0.9]] {
+            assert_gradient(&SchafferN2, xs);
+            assert_gradient(&SixHumpCamel, xs);
+        }
+    }
+
+    #[test]
+    fn sphere_works() {
+        let xs = [
+            [
+                -2.6832422185790326,
+                0.45290726703054673,
+                -1.3316590945476685,
+            ],
+            [1.064141195225031, 1.2873759140664731, -4.448984481384314],
+            [-4.985159766478089, 3.455683400667751, -2.4642148932812016],
+            [-2.720450958881829, 5.075403115627139, -0.304501682970133],
+            [3.4453652610497416, -0.24214314291880967, 1.4240577591722188],
+        ];
+        let ys = [
+            9.178229740185524,
+            22.583196143091897,
+            42.865920703231396,
+            33.253291480730375,
+            13.957115585169554,
+        ];
+
+        let f = Sphere::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn sum_of_different_powers_works() {
+        let xs = [
+            [
+                0.25468221802191193,
+                -0.06749914060658435,
+                0.3585627960762172,
+            ],
+            [-0.2948460339179053, 0.413900498731798, 0.4760685785040686],
+            [-0.9556350620183802, -0.8788463927084054, 0.3520406189747536],
+            [
+                0.9266111607725147,
+                -0.49775544363305957,
+                -0.08737574072724152,
+            ],
+            [0.1853437513329783, -0.3599492285039869, -0.2720898213200862],
+        ];
+        let ys = [
+            0.0817001124649127,
+            0.20920725263954856,
+            1.6073931118019615,
+            0.9819906576314218,
+            0.08646943495302584,
+        ];
+
+        let f = SumOfDifferentPowers::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn sum_squares_works() {
+        let xs = [
+            [-6.987671519529521, 2.6972131657037686, 7.360906142865936],
+            [0.46362420766602597, 4.825037124029805, 3.428229507391851],
+            [-8.719371235460054, 5.164604925736347, 1.8219916586263523],
+            [-3.974646809685753, -9.379764970605, 7.310544739578912],
+            [-0.5450182266906634, 4.376478481316061, 7.576256005109634],
+        ];
+        let ys = [
+            225.9262887195799,
+            82.0351865685221,
+            139.3326836319605,
+            352.0899922374771,
+            210.8031378271495,
+        ];
+
+        let f = SumSquares::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn schaffer_n2_works() {
+        let xs = [
+            [-51.627566756584976, -17.87550227055155],
+            [-92.62616303702967, -15.755807692554441],
+            [-50.282805193311496, 77.86008332347413],
+            [66.20942386347022, -0.2840560857801222],
+            [-93.66992855547971, -49.121267716009356],
+        ];
+        let ys = [
+            0.5070092988992,
+            0.49503391369423966,
+            0.4967037817420047,
+            0.5097394269322563,
+            0.4985852996331181,
+        ];
+
+        let f = SchafferN2;
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn schaffer_n4_works() {
+        let xs = [
+            [-51.52217518437337, -58.38690136027993],
+            [-53.706669185854935, 73.94190320624],
+            [-71.6596464440017, -89.74525497700947],
+            [85.6066350855182, 13.068840081775178],
+            [98.11414267079383, -19.40758680886745],
+        ];
+        let ys = [
+            0.5055619153075046,
+            0.5023612555504823,
+            0.5011857898762047,
+            0.49823774396929665,
+            0.5001116387767008,
+        ];
+
+        let f = SchafferN4;
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn six_hump_camel_works() {
+        let xs = [
+            [2.40571294663701, 0.6158938667445808],
+            [1.7451463364371271, 0.978905142560698],
+            [-0.03427186366292734, -1.6283655336467158],
+            [-1.7344722332092661, 1.4952249101491222],
+            [2.3985711311164026, 1.6983094860749115],
+        ];
+        let ys = [
+            17.967227915553977,
+            3.6684362429584745,
+            17.57759053343549,
+            10.560578780811912,
+            42.79137364640788,
+        ];
+
+        let f = SixHumpCamel;
+        assert_reference_values(&f, &xs, &ys);
+    }
+}
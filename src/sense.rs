@@ -0,0 +1,183 @@
+use crate::mfso::{self, MultiFidelitySingleObjective};
+use crate::{
+    Differentiable, Error, GlobalOptimum, Interval, Objective, Optimum, StochasticObjective,
+    VariableType,
+};
+use std::ops::Neg;
+
+/// Optimization direction of an objective.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sense {
+    /// Smaller outputs are better.
+    Minimize,
+
+    /// Larger outputs are better.
+    Maximize,
+}
+impl Sense {
+    /// Returns the opposite direction.
+    pub const fn flip(self) -> Self {
+        match self {
+            Sense::Minimize => Sense::Maximize,
+            Sense::Maximize => Sense::Minimize,
+        }
+    }
+}
+
+/// Adapter that converts an objective to the given optimization direction.
+///
+/// The outputs (and the reported optimum value) are negated if the direction of the inner objective differs from `sense`.
+#[derive(Debug, Clone)]
+pub struct WithSense<T> {
+    inner: T,
+    sense: Sense,
+}
+impl<T> WithSense<T>
+where
+    T: Objective,
+{
+    /// Makes a new `WithSense` instance.
+    pub fn new(inner: T, sense: Sense) -> Self {
+        Self { inner, sense }
+    }
+
+    /// Converts `inner` to a minimization problem.
+    pub fn minimize(inner: T) -> Self {
+        Self::new(inner, Sense::Minimize)
+    }
+
+    /// Converts `inner` to a maximization problem.
+    pub fn maximize(inner: T) -> Self {
+        Self::new(inner, Sense::Maximize)
+    }
+
+    /// Returns a reference to the wrapped objective.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the wrapped objective.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    fn is_flipped(&self) -> bool {
+        self.inner.sense() != self.sense
+    }
+
+    fn convert<V>(&self, v: V) -> V
+    where
+        V: Neg<Output = V>,
+    {
+        if self.is_flipped() {
+            -v
+        } else {
+            v
+        }
+    }
+}
+impl<T> Objective for WithSense<T>
+where
+    T: Objective,
+    T::Output: Neg<Output = T::Output>,
+{
+    type Output = T::Output;
+
+    fn input_domain(&self) -> &[Interval] {
+        self.inner.input_domain()
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> Self::Output {
+        self.convert(self.inner.evaluate(xs))
+    }
+
+    fn sense(&self) -> Sense {
+        self.sense
+    }
+
+    fn variable_types(&self) -> Vec<VariableType> {
+        self.inner.variable_types()
+    }
+
+    fn validate(&self, xs: &[f64]) -> Result<(), Error> {
+        self.inner.validate(xs)
+    }
+}
+impl<T> MultiFidelitySingleObjective for WithSense<T>
+where
+    T: MultiFidelitySingleObjective,
+{
+    fn max_cost(&self) -> mfso::Cost {
+        self.inner.max_cost()
+    }
+}
+impl<T> StochasticObjective for WithSense<T>
+where
+    T: StochasticObjective,
+    T::Output: Neg<Output = T::Output>,
+{
+    fn evaluate_with_seed(&self, xs: &[f64], seed: u64) -> Self::Output {
+        self.convert(self.inner.evaluate_with_seed(xs, seed))
+    }
+}
+impl<T> Differentiable for WithSense<T>
+where
+    T: Differentiable,
+{
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        self.inner
+            .gradient(xs)
+            .into_iter()
+            .map(|g| self.convert(g))
+            .collect()
+    }
+}
+impl<T> GlobalOptimum for WithSense<T>
+where
+    T: Objective + GlobalOptimum,
+{
+    fn global_optimum(&self) -> Option<Optimum> {
+        let optimum = self.inner.global_optimum()?;
+        Some(Optimum::new(
+            self.convert(optimum.value()),
+            optimum.inputs().to_vec(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mfso::Hartmann3d;
+    use crate::Adjiman;
+
+    #[test]
+    fn with_sense_works() {
+        let f = WithSense::maximize(Adjiman);
+        assert_eq!(f.sense(), Sense::Maximize);
+        assert_eq!(f.evaluate(&[1.0, 0.5]), -Adjiman.evaluate(&[1.0, 0.5]));
+        assert_eq!(
+            f.gradient(&[1.0, 0.5])[0],
+            -Adjiman.gradient(&[1.0, 0.5])[0]
+        );
+        assert_eq!(
+            f.global_optimum().unwrap().value(),
+            -Adjiman.global_optimum().unwrap().value()
+        );
+
+        let f = WithSense::minimize(Adjiman);
+        assert_eq!(f.evaluate(&[1.0, 0.5]), Adjiman.evaluate(&[1.0, 0.5]));
+
+        let h = Hartmann3d::default();
+        assert_eq!(h.sense(), Sense::Maximize);
+        let f = WithSense::minimize(Hartmann3d::default());
+        assert_eq!(f.sense(), Sense::Minimize);
+        let ys = h.evaluate(&[0.1, 0.2, 0.3]).collect::<Vec<_>>();
+        let flipped = f.evaluate(&[0.1, 0.2, 0.3]).collect::<Vec<_>>();
+        for ((c0, y0), (c1, y1)) in ys.into_iter().zip(flipped) {
+            assert_eq!(c0, c1);
+            assert_eq!(y0, -y1);
+        }
+        assert!(f.global_optimum().unwrap().value() < 0.0);
+    }
+}
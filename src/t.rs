@@ -0,0 +1,169 @@
+use crate::{interval, Differentiable, GlobalOptimum, Interval, Objective, Optimum};
+use std::num::NonZeroUsize;
+
+/// Three-Hump Camel Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Three-Hump Camel Function](https://www.sfu.ca/~ssurjano/camel3.html)
+#[derive(Debug, Clone)]
+pub struct ThreeHumpCamel;
+impl Objective for ThreeHumpCamel {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        const X: Interval = interval(-5.0, 5.0);
+        &[X, X]
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        2.0 * x.powi(2) - 1.05 * x.powi(4) + x.powi(6) / 6.0 + x * y + y.powi(2)
+    }
+}
+impl GlobalOptimum for ThreeHumpCamel {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0, 0.0]]))
+    }
+}
+impl Differentiable for ThreeHumpCamel {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), 2);
+
+        let (x, y) = (xs[0], xs[1]);
+        vec![4.0 * x - 4.2 * x.powi(3) + x.powi(5) + y, x + 2.0 * y]
+    }
+}
+
+/// Trid Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Trid Function](https://www.sfu.ca/~ssurjano/trid.html)
+#[derive(Debug, Clone)]
+pub struct Trid {
+    input_domain: Vec<Interval>,
+}
+impl Trid {
+    /// Makes a new `Trid` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let d = dimension.get() as f64;
+        let input_domain = (0..dimension.get())
+            .map(|_| interval(-d * d, d * d))
+            .collect();
+        Self { input_domain }
+    }
+}
+impl Objective for Trid {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        xs.iter().map(|&x| (x - 1.0).powi(2)).sum::<f64>()
+            - xs.windows(2).map(|w| w[0] * w[1]).sum::<f64>()
+    }
+}
+impl GlobalOptimum for Trid {
+    fn global_optimum(&self) -> Option<Optimum> {
+        let d = self.dimension().get();
+        let xs = (1..=d).map(|i| (i * (d + 1 - i)) as f64).collect();
+        let d = d as f64;
+        Some(Optimum::new(-d * (d + 4.0) * (d - 1.0) / 6.0, vec![xs]))
+    }
+}
+impl Differentiable for Trid {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let n = xs.len();
+        (0..n)
+            .map(|i| {
+                let mut g = 2.0 * (xs[i] - 1.0);
+                if i > 0 {
+                    g -= xs[i - 1];
+                }
+                if i + 1 < n {
+                    g -= xs[i + 1];
+                }
+                g
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_global_optimum, assert_gradient, assert_reference_values};
+
+    #[test]
+    fn trid_works() {
+        let xs = [
+            [-8.204578899668219, 2.061585513572556, -8.191075617071348],
+            [3.931928594073293, -3.042825371657864, 6.856295530452446],
+            [8.651443623180114, 0.09756672566479274, 8.973161016763978],
+            [-3.4259390374258976, -7.614527315302586, 1.7957305583388123],
+            [-8.435200280844281, -5.447072584288451, -1.6571495588941652],
+        ];
+        let ys = [
+            204.1281513588187,
+            92.0635216877008,
+            121.21069696671992,
+            82.01893735693255,
+            82.67443090197199,
+        ];
+
+        let f = Trid::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        assert_global_optimum(&Trid::new(NonZeroUsize::new(1).unwrap()));
+        assert_global_optimum(&Trid::new(NonZeroUsize::new(6).unwrap()));
+        assert_eq!(
+            Trid::new(NonZeroUsize::new(6).unwrap())
+                .global_optimum()
+                .unwrap()
+                .value(),
+            -50.0
+        );
+        assert_global_optimum(&ThreeHumpCamel);
+    }
+
+    #[test]
+    fn gradients_work() {
+        assert_gradient(&Trid::new(NonZeroUsize::new(3).unwrap()), &[0.3, -1.2, 2.5]);
+        for xs in &[[0.3, -1.2], [1.7, 0.4], [-0.5, 0.9]] {
+            assert_gradient(&ThreeHumpCamel, xs);
+        }
+    }
+
+    #[test]
+    fn three_hump_camel_works() {
+        let xs = [
+            [-1.6341041552867983, 1.5690899991414735],
+            [2.9950465933008132, 1.424939208853032],
+            [3.1482617124907044, 0.28023892754599355],
+            [1.5473218859283078, 1.8595985175001895],
+            [-2.3170095429919213, 4.227999627007682],
+        ];
+        let ys = [
+            0.9249898514221939,
+            60.0504257452896,
+            79.91635798150016,
+            7.392436333306105,
+            14.342408015381901,
+        ];
+
+        let f = ThreeHumpCamel;
+        assert_reference_values(&f, &xs, &ys);
+    }
+}
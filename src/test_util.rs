@@ -0,0 +1,155 @@
+//! Assertions shared by the tests of the objectives.
+use crate::constrained::ConstrainedObjective;
+use crate::mfso::{MultiFidelityDifferentiable, MultiFidelitySingleObjective};
+use crate::transform::sample;
+use crate::{Differentiable, FiniteDifference, GlobalOptimum, Objective};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Asserts that `f` outputs `ys[i]` at `xs[i]` (up to a relative error of `1e-12`).
+pub(crate) fn assert_reference_values<F, X>(f: &F, xs: &[X], ys: &[f64])
+where
+    F: Objective<Output = f64>,
+    X: AsRef<[f64]>,
+{
+    for (x, y) in xs.iter().zip(ys.iter()) {
+        assert!((f.evaluate(x.as_ref()) - y).abs() < 1e-12 * y.abs().max(1.0));
+    }
+}
+
+/// Asserts that `f` outputs the reported optimum value at all the reported optimum inputs (up to an error of `1e-12`).
+pub(crate) fn assert_global_optimum<F>(f: &F)
+where
+    F: Objective<Output = f64> + GlobalOptimum,
+{
+    assert_global_optimum_with_tolerance(f, 1e-12);
+}
+
+/// Same as `assert_global_optimum` except that the error is allowed up to `tolerance`.
+pub(crate) fn assert_global_optimum_with_tolerance<F>(f: &F, tolerance: f64)
+where
+    F: Objective<Output = f64> + GlobalOptimum,
+{
+    let optimum = f.global_optimum().unwrap();
+    for xs in optimum.inputs() {
+        assert!(f.validate(xs).is_ok());
+        assert!((f.evaluate(xs) - optimum.value()).abs() < tolerance);
+    }
+}
+
+/// Same as `assert_global_optimum_with_tolerance` except that it also asserts that no sampled input
+/// outputs less than the reported optimum value (minus `tolerance`).
+pub(crate) fn assert_sampled_global_optimum<F>(f: &F, tolerance: f64)
+where
+    F: Objective<Output = f64> + GlobalOptimum,
+{
+    assert_global_optimum_with_tolerance(f, tolerance);
+    let value = f.global_optimum().unwrap().value();
+    assert_no_better_samples(f, value - tolerance, |_| true);
+}
+
+/// Asserts that the reported optimum inputs of `f` are feasible (up to `tolerance`) and
+/// that no sampled feasible input outputs less than the reported optimum value.
+pub(crate) fn assert_constrained_optimum<F>(f: &F, tolerance: f64)
+where
+    F: ConstrainedObjective + GlobalOptimum,
+{
+    assert_global_optimum_with_tolerance(f, tolerance);
+    let optimum = f.global_optimum().unwrap();
+    for xs in optimum.inputs() {
+        assert!(
+            f.is_feasible(xs, tolerance),
+            "{:?}",
+            f.inequality_constraints(xs)
+        );
+    }
+    assert_no_better_samples(f, optimum.value(), |xs| f.is_feasible(xs, 0.0));
+}
+
+/// Asserts that `f` outputs at least `value` at every input uniformly sampled from the input domain and accepted by `accept`.
+pub(crate) fn assert_no_better_samples<F, A>(f: &F, value: f64, accept: A)
+where
+    F: Objective<Output = f64>,
+    A: Fn(&[f64]) -> bool,
+{
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..10_000 {
+        let xs = f
+            .input_domain()
+            .iter()
+            .map(|i| sample(i, &mut rng))
+            .collect::<Vec<_>>();
+        if accept(&xs) {
+            let y = f.evaluate(&xs);
+            assert!(y >= value, "f({:?}) = {}", xs, y);
+        }
+    }
+}
+
+/// Asserts that the gradient of `f` at `xs` agrees with the finite-difference approximation
+/// (up to a relative error of `1e-5`).
+pub(crate) fn assert_gradient<F>(f: &F, xs: &[f64])
+where
+    F: Differentiable + Clone,
+{
+    assert_gradient_with_tolerance(f, xs, 1e-5);
+}
+
+/// Same as `assert_gradient` except that the relative error is allowed up to `tolerance`.
+pub(crate) fn assert_gradient_with_tolerance<F>(f: &F, xs: &[f64], tolerance: f64)
+where
+    F: Differentiable + Clone,
+{
+    let expected = FiniteDifference::new(f.clone()).gradient(xs);
+    let actual = f.gradient(xs);
+    for (e, a) in expected.iter().zip(actual.iter()) {
+        assert!(
+            (e - a).abs() < tolerance * e.abs().max(1.0),
+            "{} != {}",
+            e,
+            a
+        );
+    }
+}
+
+/// Asserts that the highest fidelity output of `f` is the reported optimum value at all the reported optimum inputs.
+pub(crate) fn assert_multi_fidelity_global_optimum<F>(f: &F)
+where
+    F: MultiFidelitySingleObjective + GlobalOptimum,
+{
+    let optimum = f.global_optimum().unwrap();
+    for xs in optimum.inputs() {
+        let (_, y) = f.evaluate(xs).last().unwrap();
+        assert!((y - optimum.value()).abs() < 1e-9);
+    }
+}
+
+/// Asserts that the gradients of `f` at `xs` agree with the central differences at each fidelity.
+pub(crate) fn assert_multi_fidelity_gradients<F>(f: &F, xs: &[f64])
+where
+    F: MultiFidelityDifferentiable,
+{
+    let mut xs = xs.to_vec();
+    let gradients = f.gradients(&xs);
+    for j in 0..xs.len() {
+        let x = xs[j];
+        let h = 1e-6 * x.abs().max(1.0);
+        xs[j] = x + h;
+        let ys0 = f.evaluate(&xs).collect::<Vec<_>>();
+        xs[j] = x - h;
+        let ys1 = f.evaluate(&xs).collect::<Vec<_>>();
+        xs[j] = x;
+
+        assert_eq!(gradients.len(), ys0.len());
+        for ((c, g), ((c0, y0), (_, y1))) in gradients.iter().zip(ys0.iter().zip(ys1.iter())) {
+            let expected = (y0 - y1) / (2.0 * h);
+            assert_eq!(c, c0);
+            assert!(
+                (g[j] - expected).abs() < 1e-5 * expected.abs().max(1.0),
+                "{} != {}",
+                g[j],
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,1115 @@
+//! Wrappers that transform the input space of objectives.
+//!
+//! Shifting or rotating an objective can map inputs out of the input domain of the inner objective.
+//! Such inputs are handled as in BBOB: the inner objective is evaluated at the nearest point within its domain,
+//! and the output is penalized by the squared distance to that point (see `Penalize`).
+//! Therefore, the global optimum of a transformed objective is never worse than the reported one.
+use crate::mfso::{self, MultiFidelitySingleObjective};
+use crate::{
+    Differentiable, Error, GlobalOptimum, Interval, Objective, Optimum, Sense, StochasticObjective,
+    VariableType,
+};
+use rand::distributions::{Distribution, Normal};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::iter;
+use std::num::NonZeroUsize;
+
+/// Wrapper that shifts the input space of an objective.
+///
+/// The output at `xs` is the output of the inner objective at `xs - shift`,
+/// so the global optimum moves by `shift`.
+/// Points `xs - shift` out of the input domain are penalized as described in the module documentation.
+#[derive(Debug, Clone)]
+pub struct Shifted<T> {
+    inner: T,
+    shift: Vec<f64>,
+}
+impl<T> Shifted<T>
+where
+    T: Objective,
+{
+    /// Makes a new `Shifted` instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `shift` is different from the dimension of `inner`.
+    pub fn new(inner: T, shift: Vec<f64>) -> Self {
+        assert_eq!(shift.len(), inner.dimension().get());
+        Self { inner, shift }
+    }
+
+    /// Makes a new `Shifted` instance that moves the (first) global optimum of `inner` to `location`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the global optimum of `inner` is unknown, the length of `location` is different from the dimension,
+    /// or `location` is out of the input domain.
+    pub fn with_optimum_at(inner: T, location: Vec<f64>) -> Self
+    where
+        T: GlobalOptimum,
+    {
+        assert_eq!(location.len(), inner.dimension().get());
+        assert!(location
+            .iter()
+            .zip(inner.input_domain().iter())
+            .all(|(&x, i)| i.contains(x)));
+        let optimum = inner.global_optimum().unwrap_or_else(|| panic!());
+        let shift = location
+            .iter()
+            .zip(optimum.inputs()[0].iter())
+            .map(|(&l, &x)| l - x)
+            .collect();
+        Self::new(inner, shift)
+    }
+
+    /// Makes a new `Shifted` instance that moves the (first) global optimum of `inner`
+    /// to a location sampled uniformly from the input domain by using `seed`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the global optimum of `inner` is unknown.
+    pub fn random(inner: T, seed: u64) -> Self
+    where
+        T: GlobalOptimum,
+    {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let location = inner
+            .input_domain()
+            .iter()
+            .map(|i| sample(i, &mut rng))
+            .collect();
+        Self::with_optimum_at(inner, location)
+    }
+
+    /// Returns the shift vector.
+    pub fn shift(&self) -> &[f64] {
+        &self.shift
+    }
+
+    pub(crate) fn shift_mut(&mut self) -> &mut [f64] {
+        &mut self.shift
+    }
+
+    /// Returns a reference to the wrapped objective.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the wrapped objective.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    fn unshift(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.shift.len());
+        xs.iter()
+            .zip(self.shift.iter())
+            .map(|(x, s)| x - s)
+            .collect()
+    }
+}
+impl<T> Objective for Shifted<T>
+where
+    T: Objective,
+    T::Output: Penalize,
+{
+    type Output = T::Output;
+
+    fn input_domain(&self) -> &[Interval] {
+        self.inner.input_domain()
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> Self::Output {
+        evaluate_clamped(&self.inner, &self.unshift(xs), |zs| self.inner.evaluate(zs))
+    }
+
+    fn sense(&self) -> Sense {
+        self.inner.sense()
+    }
+    /// Returns the types of the input variables, whose feasible values are shifted by `shift`.
+    fn variable_types(&self) -> Vec<VariableType> {
+        self.inner
+            .variable_types()
+            .iter()
+            .zip(self.inner.input_domain().iter())
+            .zip(self.shift.iter())
+            .map(|((t, &domain), &s)| t.pull_back(domain, domain, |z| z + s))
+            .collect()
+    }
+
+    fn validate(&self, xs: &[f64]) -> Result<(), Error> {
+        crate::validate_domain(self.input_domain(), xs)?;
+        validate_clamped(&self.inner, &self.unshift(xs)).map_err(|e| off_grid_at(e, xs))
+    }
+}
+impl<T> MultiFidelitySingleObjective for Shifted<T>
+where
+    T: MultiFidelitySingleObjective,
+{
+    fn max_cost(&self) -> mfso::Cost {
+        self.inner.max_cost()
+    }
+}
+impl<T> StochasticObjective for Shifted<T>
+where
+    T: StochasticObjective,
+    T::Output: Penalize,
+{
+    fn evaluate_with_seed(&self, xs: &[f64], seed: u64) -> Self::Output {
+        evaluate_clamped(&self.inner, &self.unshift(xs), |zs| {
+            self.inner.evaluate_with_seed(zs, seed)
+        })
+    }
+}
+impl<T> Differentiable for Shifted<T>
+where
+    T: Differentiable,
+{
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        gradient_clamped(&self.inner, &self.unshift(xs))
+    }
+}
+impl<T> GlobalOptimum for Shifted<T>
+where
+    T: Objective + GlobalOptimum,
+{
+    /// Returns the shifted optimum.
+    ///
+    /// Only the optimum inputs shifted within the input domain are reported, and `None` is returned if there is no such input
+    /// (`Shifted::with_optimum_at` and `Shifted::random` always keep the first one).
+    fn global_optimum(&self) -> Option<Optimum> {
+        let optimum = self.inner.global_optimum()?;
+        let inputs = optimum
+            .inputs()
+            .iter()
+            .map(|xs| {
+                xs.iter()
+                    .zip(self.shift.iter())
+                    .map(|(x, s)| x + s)
+                    .collect()
+            })
+            .collect();
+        within_domain(self.inner.input_domain(), optimum.value(), inputs)
+    }
+}
+
+/// Wrapper that rotates the input space of an objective around a center point.
+///
+/// The output at `xs` is the output of the inner objective at `center + matrix * (xs - center)`.
+/// Points out of the input domain are penalized as described in the module documentation.
+#[derive(Debug, Clone)]
+pub struct Rotated<T> {
+    inner: T,
+    matrix: Vec<Vec<f64>>,
+    center: Vec<f64>,
+}
+impl<T> Rotated<T>
+where
+    T: Objective,
+{
+    /// Makes a new `Rotated` instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is not an orthogonal matrix or the length of `center` is different from the dimension of `inner`.
+    pub fn new(inner: T, matrix: Vec<Vec<f64>>, center: Vec<f64>) -> Self {
+        let dimension = inner.dimension().get();
+        assert_eq!(center.len(), dimension);
+        assert_eq!(matrix.len(), dimension);
+        assert!(matrix.iter().all(|row| row.len() == dimension));
+        for i in 0..dimension {
+            for j in 0..dimension {
+                let dot = dot(&matrix[i], &matrix[j]);
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((dot - expected).abs() < 1e-9);
+            }
+        }
+        Self {
+            inner,
+            matrix,
+            center,
+        }
+    }
+
+    /// Makes a new `Rotated` instance that rotates `inner` around its (first) global optimum
+    /// by using a random orthogonal matrix generated from `seed`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the global optimum of `inner` is unknown.
+    pub fn random(inner: T, seed: u64) -> Self
+    where
+        T: GlobalOptimum,
+    {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let matrix = random_orthogonal_matrix(inner.dimension().get(), &mut rng);
+        let optimum = inner.global_optimum().unwrap_or_else(|| panic!());
+        let center = optimum.inputs()[0].clone();
+        Self::new(inner, matrix, center)
+    }
+
+    /// Returns the rotation matrix.
+    pub fn matrix(&self) -> &[Vec<f64>] {
+        &self.matrix
+    }
+
+    /// Returns the center of the rotation.
+    pub fn center(&self) -> &[f64] {
+        &self.center
+    }
+
+    /// Returns a reference to the wrapped objective.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the wrapped objective.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    fn rotate(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.center.len());
+        let ds = xs
+            .iter()
+            .zip(self.center.iter())
+            .map(|(x, c)| x - c)
+            .collect::<Vec<_>>();
+        self.matrix
+            .iter()
+            .zip(self.center.iter())
+            .map(|(row, c)| c + dot(row, &ds))
+            .collect()
+    }
+
+    fn unrotate(&self, xs: &[f64]) -> Vec<f64> {
+        let ds = xs
+            .iter()
+            .zip(self.center.iter())
+            .map(|(x, c)| x - c)
+            .collect::<Vec<_>>();
+        transpose_mul(&self.matrix, &ds)
+            .into_iter()
+            .zip(self.center.iter())
+            .map(|(d, c)| c + d)
+            .collect()
+    }
+}
+impl<T> Objective for Rotated<T>
+where
+    T: Objective,
+    T::Output: Penalize,
+{
+    type Output = T::Output;
+
+    fn input_domain(&self) -> &[Interval] {
+        self.inner.input_domain()
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> Self::Output {
+        evaluate_clamped(&self.inner, &self.rotate(xs), |zs| self.inner.evaluate(zs))
+    }
+
+    fn sense(&self) -> Sense {
+        self.inner.sense()
+    }
+
+    /// Returns `VariableType::Continuous` for all the variables since a rotation mixes the coordinates.
+    ///
+    /// Note that `validate` still checks the variable types of the inner objective at the rotated point.
+    fn variable_types(&self) -> Vec<VariableType> {
+        vec![VariableType::Continuous; self.center.len()]
+    }
+
+    fn validate(&self, xs: &[f64]) -> Result<(), Error> {
+        crate::validate_domain(self.input_domain(), xs)?;
+        validate_clamped(&self.inner, &self.rotate(xs))
+    }
+}
+impl<T> MultiFidelitySingleObjective for Rotated<T>
+where
+    T: MultiFidelitySingleObjective,
+{
+    fn max_cost(&self) -> mfso::Cost {
+        self.inner.max_cost()
+    }
+}
+impl<T> StochasticObjective for Rotated<T>
+where
+    T: StochasticObjective,
+    T::Output: Penalize,
+{
+    fn evaluate_with_seed(&self, xs: &[f64], seed: u64) -> Self::Output {
+        evaluate_clamped(&self.inner, &self.rotate(xs), |zs| {
+            self.inner.evaluate_with_seed(zs, seed)
+        })
+    }
+}
+impl<T> Differentiable for Rotated<T>
+where
+    T: Differentiable,
+{
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        let g = gradient_clamped(&self.inner, &self.rotate(xs));
+        transpose_mul(&self.matrix, &g)
+    }
+}
+impl<T> GlobalOptimum for Rotated<T>
+where
+    T: Objective + GlobalOptimum,
+{
+    /// Returns the rotated optimum.
+    ///
+    /// Only the optimum inputs rotated within the input domain are reported, and `None` is returned if there is no such input
+    /// (`Rotated::random` always keeps the first one since it is the center of the rotation).
+    fn global_optimum(&self) -> Option<Optimum> {
+        let optimum = self.inner.global_optimum()?;
+        let inputs = optimum
+            .inputs()
+            .iter()
+            .map(|xs| self.unrotate(xs))
+            .collect();
+        within_domain(self.inner.input_domain(), optimum.value(), inputs)
+    }
+}
+
+/// Wrapper that exposes an objective on the unit hypercube `[0, 1]^d`.
+///
+/// The output at `us` is the output of the inner objective at `to_native(us)`.
+#[derive(Debug, Clone)]
+pub struct Normalized<T> {
+    inner: T,
+    input_domain: Vec<Interval>,
+}
+impl<T> Normalized<T>
+where
+    T: Objective,
+{
+    /// Makes a new `Normalized` instance.
+    pub fn new(inner: T) -> Self {
+        let input_domain = (0..inner.dimension().get())
+            .map(|_| unsafe { Interval::new_unchecked(0.0, 1.0) })
+            .collect();
+        Self {
+            inner,
+            input_domain,
+        }
+    }
+
+    /// Maps `us` in the unit hypercube to the native input domain.
+    pub fn to_native(&self, us: &[f64]) -> Vec<f64> {
+        assert_eq!(us.len(), self.input_domain.len());
+        us.iter()
+            .zip(self.inner.input_domain().iter())
+            .map(|(&u, i)| i.min() + u * (i.max() - i.min()))
+            .collect()
+    }
+
+    /// Maps `xs` in the native input domain to the unit hypercube.
+    ///
+    /// Elements of degenerate (zero width) intervals are mapped to `0`.
+    pub fn to_unit(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.input_domain.len());
+        xs.iter()
+            .zip(self.inner.input_domain().iter())
+            .map(|(&x, i)| {
+                let width = i.max() - i.min();
+                if width == 0.0 {
+                    0.0
+                } else {
+                    (x - i.min()) / width
+                }
+            })
+            .collect()
+    }
+
+    /// Returns a reference to the wrapped objective.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the wrapped objective.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+impl<T> Objective for Normalized<T>
+where
+    T: Objective,
+{
+    type Output = T::Output;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, us: &[f64]) -> Self::Output {
+        self.inner.evaluate(&self.to_native(us))
+    }
+
+    fn sense(&self) -> Sense {
+        self.inner.sense()
+    }
+
+    /// Returns the types of the input variables, whose feasible values are mapped to the unit hypercube.
+    fn variable_types(&self) -> Vec<VariableType> {
+        self.inner
+            .variable_types()
+            .iter()
+            .zip(self.inner.input_domain().iter())
+            .zip(self.input_domain.iter())
+            .map(|((t, &native), &unit)| {
+                t.pull_back(native, unit, |x| {
+                    let width = native.max() - native.min();
+                    if width == 0.0 {
+                        0.0
+                    } else {
+                        (x - native.min()) / width
+                    }
+                })
+            })
+            .collect()
+    }
+
+    fn validate(&self, us: &[f64]) -> Result<(), Error> {
+        crate::validate_domain(self.input_domain(), us)?;
+        validate_clamped(&self.inner, &self.to_native(us)).map_err(|e| off_grid_at(e, us))
+    }
+}
+impl<T> MultiFidelitySingleObjective for Normalized<T>
+where
+    T: MultiFidelitySingleObjective,
+{
+    fn max_cost(&self) -> mfso::Cost {
+        self.inner.max_cost()
+    }
+}
+impl<T> StochasticObjective for Normalized<T>
+where
+    T: StochasticObjective,
+{
+    fn evaluate_with_seed(&self, xs: &[f64], seed: u64) -> Self::Output {
+        self.inner.evaluate_with_seed(&self.to_native(xs), seed)
+    }
+}
+impl<T> Differentiable for Normalized<T>
+where
+    T: Differentiable,
+{
+    fn gradient(&self, us: &[f64]) -> Vec<f64> {
+        self.inner
+            .gradient(&self.to_native(us))
+            .into_iter()
+            .zip(self.inner.input_domain().iter())
+            .map(|(g, i)| g * (i.max() - i.min()))
+            .collect()
+    }
+}
+impl<T> GlobalOptimum for Normalized<T>
+where
+    T: Objective + GlobalOptimum,
+{
+    fn global_optimum(&self) -> Option<Optimum> {
+        let optimum = self.inner.global_optimum()?;
+        let inputs = optimum.inputs().iter().map(|xs| self.to_unit(xs)).collect();
+        Some(Optimum::new(optimum.value(), inputs))
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Embedding {
+    Dummy,
+    Linear { matrix: Vec<Vec<f64>> },
+}
+
+/// Wrapper that embeds a low-dimensional objective into a higher-dimensional input space.
+#[derive(Debug, Clone)]
+pub struct Embedded<T> {
+    inner: T,
+    input_domain: Vec<Interval>,
+    embedding: Embedding,
+}
+impl<T> Embedded<T>
+where
+    T: Objective,
+{
+    /// Makes a new `Embedded` instance that appends dummy dimensions to `inner`.
+    ///
+    /// The first dimensions are the ones of `inner`, and the rest `dimension - inner.dimension()` dimensions,
+    /// whose domains are `[0, 1]`, don't affect outputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is less than the dimension of `inner`.
+    pub fn dummy(inner: T, dimension: NonZeroUsize) -> Self {
+        assert!(inner.dimension() <= dimension);
+        let input_domain = inner
+            .input_domain()
+            .iter()
+            .copied()
+            .chain(iter::repeat(unsafe { Interval::new_unchecked(0.0, 1.0) }))
+            .take(dimension.get())
+            .collect();
+        Self {
+            inner,
+            input_domain,
+            embedding: Embedding::Dummy,
+        }
+    }
+
+    /// Makes a new `Embedded` instance that embeds `inner` into `[-1, 1]^dimension` through a random linear map.
+    ///
+    /// An input `xs` is mapped to `inner` as follows:
+    /// 1. project `xs` by a random `inner.dimension() x dimension` matrix `B` having orthonormal rows,
+    /// 2. clip the projected point to `[-1, 1]^inner.dimension()`, and
+    /// 3. map the clipped point to the input domain of `inner` linearly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is less than the dimension of `inner`.
+    pub fn random_linear(inner: T, dimension: NonZeroUsize, seed: u64) -> Self {
+        assert!(inner.dimension() <= dimension);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut matrix = random_orthogonal_matrix(dimension.get(), &mut rng);
+        matrix.truncate(inner.dimension().get());
+        let input_domain = (0..dimension.get())
+            .map(|_| unsafe { Interval::new_unchecked(-1.0, 1.0) })
+            .collect();
+        Self {
+            inner,
+            input_domain,
+            embedding: Embedding::Linear { matrix },
+        }
+    }
+
+    /// Maps `xs` in the embedding space to the input space of the inner objective.
+    pub fn project(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.input_domain.len());
+        match &self.embedding {
+            Embedding::Dummy => xs[..self.inner.dimension().get()].to_vec(),
+            Embedding::Linear { matrix } => matrix
+                .iter()
+                .zip(self.inner.input_domain().iter())
+                .map(|(row, i)| {
+                    let u = dot(row, xs).clamp(-1.0, 1.0);
+                    (i.min() + i.max()) / 2.0 + u * (i.max() - i.min()) / 2.0
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns a reference to the wrapped objective.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the wrapped objective.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Maps `zs` in the input space of the inner objective to a point in the embedding space.
+    ///
+    /// Returns `None` if no point within the input domain is mapped to `zs`.
+    fn lift(&self, zs: &[f64]) -> Option<Vec<f64>> {
+        match &self.embedding {
+            Embedding::Dummy => Some(
+                zs.iter()
+                    .copied()
+                    .chain(iter::repeat(0.5))
+                    .take(self.input_domain.len())
+                    .collect(),
+            ),
+            Embedding::Linear { matrix } => {
+                let us = zs
+                    .iter()
+                    .zip(self.inner.input_domain().iter())
+                    .map(|(&z, i)| {
+                        let half_width = (i.max() - i.min()) / 2.0;
+                        if half_width == 0.0 {
+                            0.0
+                        } else {
+                            (z - (i.min() + i.max()) / 2.0) / half_width
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                feasible_pre_image(matrix, &us)
+            }
+        }
+    }
+}
+impl<T> Objective for Embedded<T>
+where
+    T: Objective,
+{
+    type Output = T::Output;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> Self::Output {
+        self.inner.evaluate(&self.project(xs))
+    }
+
+    fn sense(&self) -> Sense {
+        self.inner.sense()
+    }
+
+    /// Returns the types of the input variables.
+    ///
+    /// The dummy dimensions of `Embedded::dummy` are continuous.
+    /// All the variables of `Embedded::random_linear` are reported as continuous since the linear map mixes the coordinates,
+    /// but `validate` still checks the variable types of the inner objective at the projected point.
+    fn variable_types(&self) -> Vec<VariableType> {
+        let types = match self.embedding {
+            Embedding::Dummy => self.inner.variable_types(),
+            Embedding::Linear { .. } => Vec::new(),
+        };
+        types
+            .into_iter()
+            .chain(iter::repeat(VariableType::Continuous))
+            .take(self.input_domain.len())
+            .collect()
+    }
+
+    fn validate(&self, xs: &[f64]) -> Result<(), Error> {
+        crate::validate_domain(self.input_domain(), xs)?;
+        let result = validate_clamped(&self.inner, &self.project(xs));
+        match self.embedding {
+            Embedding::Dummy => result.map_err(|e| off_grid_at(e, xs)),
+            Embedding::Linear { .. } => result,
+        }
+    }
+}
+impl<T> MultiFidelitySingleObjective for Embedded<T>
+where
+    T: MultiFidelitySingleObjective,
+{
+    fn max_cost(&self) -> mfso::Cost {
+        self.inner.max_cost()
+    }
+}
+impl<T> StochasticObjective for Embedded<T>
+where
+    T: StochasticObjective,
+{
+    fn evaluate_with_seed(&self, xs: &[f64], seed: u64) -> Self::Output {
+        self.inner.evaluate_with_seed(&self.project(xs), seed)
+    }
+}
+impl<T> Differentiable for Embedded<T>
+where
+    T: Differentiable,
+{
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        let g = self.inner.gradient(&self.project(xs));
+        match &self.embedding {
+            Embedding::Dummy => g
+                .into_iter()
+                .chain(iter::repeat(0.0))
+                .take(xs.len())
+                .collect(),
+            Embedding::Linear { matrix } => {
+                let mut ys = vec![0.0; xs.len()];
+                for ((row, g), i) in matrix
+                    .iter()
+                    .zip(g.iter())
+                    .zip(self.inner.input_domain().iter())
+                {
+                    let u = dot(row, xs);
+                    if !(-1.0..=1.0).contains(&u) {
+                        continue;
+                    }
+                    let k = g * (i.max() - i.min()) / 2.0;
+                    for (y, &r) in ys.iter_mut().zip(row.iter()) {
+                        *y += k * r;
+                    }
+                }
+                ys
+            }
+        }
+    }
+}
+impl<T> GlobalOptimum for Embedded<T>
+where
+    T: Objective + GlobalOptimum,
+{
+    /// Returns the embedded optimum.
+    ///
+    /// Every point in the embedding space that is mapped to an optimum input of the inner objective is an optimum,
+    /// and this method reports one representative for each of the inner optimum inputs:
+    /// the dummy dimensions are set to `0.5` for `Embedded::dummy`,
+    /// and a pre-image within the input domain found by alternating projections is used for `Embedded::random_linear`.
+    ///
+    /// Inner optimum inputs having no pre-image within the input domain are excluded,
+    /// and `None` is returned if there is no input left.
+    fn global_optimum(&self) -> Option<Optimum> {
+        let optimum = self.inner.global_optimum()?;
+        let inputs = optimum
+            .inputs()
+            .iter()
+            .filter_map(|zs| self.lift(zs))
+            .collect::<Vec<_>>();
+        if inputs.is_empty() {
+            None
+        } else {
+            Some(Optimum::new(optimum.value(), inputs))
+        }
+    }
+}
+
+/// Output that can be penalized for inputs mapped out of the input domain of an objective.
+pub trait Penalize {
+    /// Worsens this output by `penalty` (a non-negative number) according to `sense`.
+    fn penalize(self, penalty: f64, sense: Sense) -> Self;
+}
+impl Penalize for f64 {
+    fn penalize(self, penalty: f64, sense: Sense) -> Self {
+        match sense {
+            Sense::Minimize => self + penalty,
+            Sense::Maximize => self - penalty,
+        }
+    }
+}
+impl Penalize for mfso::Outputs {
+    fn penalize(self, penalty: f64, sense: Sense) -> Self {
+        if penalty == 0.0 {
+            self
+        } else {
+            mfso::Outputs::new(self.map(move |(c, v)| (c, v.penalize(penalty, sense))))
+        }
+    }
+}
+
+/// Clamps `zs` to `domain` and returns the clamped point and the squared distance between them.
+pub(crate) fn clamp(domain: &[Interval], zs: &[f64]) -> (Vec<f64>, f64) {
+    let clamped = zs
+        .iter()
+        .zip(domain.iter())
+        .map(|(&z, i)| z.clamp(i.min(), i.max()))
+        .collect::<Vec<_>>();
+    let penalty = zs
+        .iter()
+        .zip(clamped.iter())
+        .map(|(z, c)| (z - c).powi(2))
+        .sum();
+    (clamped, penalty)
+}
+
+/// Evaluates `inner` at `zs` with the boundary handling described in the module documentation.
+pub(crate) fn evaluate_clamped<T, F>(inner: &T, zs: &[f64], evaluate: F) -> T::Output
+where
+    T: Objective,
+    T::Output: Penalize,
+    F: FnOnce(&[f64]) -> T::Output,
+{
+    let (clamped, penalty) = clamp(inner.input_domain(), zs);
+    evaluate(&clamped).penalize(penalty, inner.sense())
+}
+
+/// Returns the gradient of `inner` at `zs` with the boundary handling described in the module documentation.
+pub(crate) fn gradient_clamped<T>(inner: &T, zs: &[f64]) -> Vec<f64>
+where
+    T: Differentiable,
+{
+    let (clamped, _) = clamp(inner.input_domain(), zs);
+    let sign = match inner.sense() {
+        Sense::Minimize => 1.0,
+        Sense::Maximize => -1.0,
+    };
+    inner
+        .gradient(&clamped)
+        .into_iter()
+        .zip(zs.iter().zip(clamped.iter()))
+        .map(|(g, (z, c))| if z == c { g } else { sign * 2.0 * (z - c) })
+        .collect()
+}
+
+/// Finds `xs` in `[-1, 1]^n` such that `clamp(matrix * xs, -1, 1) == us` by alternating projections,
+/// where `matrix` has orthonormal rows.
+///
+/// The search starts from the minimum-norm pre-image `matrix^T * us`, and returns `None` if it does not converge.
+fn feasible_pre_image(matrix: &[Vec<f64>], us: &[f64]) -> Option<Vec<f64>> {
+    const MAX_ITERATIONS: usize = 10_000;
+    const TOLERANCE: f64 = 1e-12;
+
+    // Projected values clipped to `us[i]` when `us[i]` is on the boundary of `[-1, 1]`.
+    let target = |i: usize, v: f64| {
+        if us[i] >= 1.0 {
+            v.max(1.0)
+        } else if us[i] <= -1.0 {
+            v.min(-1.0)
+        } else {
+            us[i]
+        }
+    };
+
+    let mut xs = transpose_mul(matrix, us);
+    for _ in 0..MAX_ITERATIONS {
+        for x in &mut xs {
+            *x = x.clamp(-1.0, 1.0);
+        }
+        let residuals = matrix
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let v = dot(row, &xs);
+                target(i, v) - v
+            })
+            .collect::<Vec<_>>();
+        if residuals.iter().all(|r| r.abs() <= TOLERANCE) {
+            return Some(xs);
+        }
+        for (x, d) in xs.iter_mut().zip(transpose_mul(matrix, &residuals)) {
+            *x += d;
+        }
+    }
+    None
+}
+
+/// Validates `zs` by `inner` after the boundary handling described in the module documentation.
+pub(crate) fn validate_clamped<T>(inner: &T, zs: &[f64]) -> Result<(), Error>
+where
+    T: Objective,
+{
+    let (clamped, _) = clamp(inner.input_domain(), zs);
+    inner.validate(&clamped)
+}
+
+/// Replaces the value of `Error::OffGrid` with the corresponding element of `xs`.
+pub(crate) fn off_grid_at(error: Error, xs: &[f64]) -> Error {
+    match error {
+        Error::OffGrid { index, .. } => Error::OffGrid {
+            index,
+            value: xs[index],
+        },
+        e => e,
+    }
+}
+
+/// Generates a random orthogonal matrix by applying the Gram-Schmidt process to a Gaussian random matrix.
+pub(crate) fn random_orthogonal_matrix<R>(dimension: usize, rng: &mut R) -> Vec<Vec<f64>>
+where
+    R: Rng + ?Sized,
+{
+    let normal = Normal::new(0.0, 1.0);
+    let mut matrix: Vec<Vec<f64>> = Vec::with_capacity(dimension);
+    while matrix.len() < dimension {
+        let mut v = (0..dimension)
+            .map(|_| normal.sample(rng))
+            .collect::<Vec<_>>();
+        for row in &matrix {
+            let d = dot(row, &v);
+            for (x, r) in v.iter_mut().zip(row.iter()) {
+                *x -= d * r;
+            }
+        }
+        let norm = dot(&v, &v).sqrt();
+        if norm < 1e-6 {
+            continue;
+        }
+        matrix.push(v.into_iter().map(|x| x / norm).collect());
+    }
+    matrix
+}
+
+pub(crate) fn sample<R>(interval: &Interval, rng: &mut R) -> f64
+where
+    R: Rng + ?Sized,
+{
+    if interval.min() == interval.max() {
+        interval.min()
+    } else {
+        rng.gen_range(interval.min(), interval.max())
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+}
+
+fn transpose_mul(matrix: &[Vec<f64>], xs: &[f64]) -> Vec<f64> {
+    let mut ys = vec![0.0; matrix.first().map_or(0, |row| row.len())];
+    for (row, &x) in matrix.iter().zip(xs.iter()) {
+        for (y, &r) in ys.iter_mut().zip(row.iter()) {
+            *y += r * x;
+        }
+    }
+    ys
+}
+
+/// Makes an optimum from the `inputs` within `domain`, or returns `None` if there is no such input.
+fn within_domain(domain: &[Interval], value: f64, inputs: Vec<Vec<f64>>) -> Option<Optimum> {
+    let inputs = inputs
+        .into_iter()
+        .filter(|xs| xs.iter().zip(domain.iter()).all(|(&x, i)| i.contains(x)))
+        .collect::<Vec<_>>();
+    if inputs.is_empty() {
+        None
+    } else {
+        Some(Optimum::new(value, inputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_gradient, assert_sampled_global_optimum};
+    use crate::{Ackley, Adjiman, Branin, Discretized, OffGridPolicy};
+
+    #[test]
+    fn shifted_works() {
+        let f = Shifted::random(Ackley::new(NonZeroUsize::new(3).unwrap()), 10);
+        assert_sampled_global_optimum(&f, 1e-9);
+        assert_gradient(&f, &[1.0, -2.0, 3.0]);
+        assert_eq!(
+            Shifted::random(Ackley::new(NonZeroUsize::new(3).unwrap()), 10).shift(),
+            f.shift()
+        );
+
+        let f = Shifted::with_optimum_at(Adjiman, vec![1.0, 0.5]);
+        assert_eq!(f.global_optimum().unwrap().inputs(), &[vec![1.0, 0.5]][..]);
+        assert_sampled_global_optimum(&f, 1e-9);
+        assert!((f.evaluate(&[1.5, 0.5]) - (f.evaluate(&[1.0, 0.5]) + 0.25)).abs() < 1e-12);
+        assert_gradient(&f, &[1.5, 0.5]);
+
+        let f = Shifted::with_optimum_at(Branin, vec![8.0, 2.0]);
+        let inputs = f.global_optimum().unwrap().inputs().to_vec();
+        assert_eq!(inputs.len(), 1);
+        assert!((inputs[0][0] - 8.0).abs() < 1e-12 && (inputs[0][1] - 2.0).abs() < 1e-12);
+        assert_sampled_global_optimum(&f, 1e-9);
+    }
+
+    #[test]
+    fn rotated_works() {
+        let f = Rotated::random(Ackley::new(NonZeroUsize::new(4).unwrap()), 3);
+        assert_sampled_global_optimum(&f, 1e-9);
+        assert_gradient(&f, &[1.0, -2.0, 3.0, 0.5]);
+
+        let f = Rotated::random(Adjiman, 5);
+        assert_sampled_global_optimum(&f, 1e-9);
+        assert_gradient(&f, &[1.0, 0.2]);
+
+        let f = Shifted::random(Rotated::random(Adjiman, 5), 7);
+        assert_sampled_global_optimum(&f, 1e-9);
+    }
+
+    #[test]
+    fn normalized_works() {
+        let f = Normalized::new(Adjiman);
+        assert!(f
+            .input_domain()
+            .iter()
+            .all(|i| i.min() == 0.0 && i.max() == 1.0));
+        assert_eq!(f.to_native(&[0.0, 1.0]), vec![-1.0, 1.0]);
+        assert_eq!(f.to_unit(&[0.5, 0.0]), vec![0.5, 0.5]);
+        assert_eq!(f.to_unit(&f.to_native(&[0.25, 0.75])), vec![0.25, 0.75]);
+        assert_sampled_global_optimum(&f, 1e-9);
+        assert_gradient(&f, &[0.3, 0.6]);
+
+        let f = Normalized::new(crate::mfso::Borehole::default());
+        let outputs = f.evaluate(&[0.5; 8]).collect::<Vec<_>>();
+        let native = f.to_native(&[0.5; 8]);
+        assert_eq!(outputs, f.inner().evaluate(&native).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn embedded_works() {
+        let dimension = NonZeroUsize::new(20).unwrap();
+
+        let f = Embedded::dummy(Adjiman, dimension);
+        assert_eq!(f.dimension(), dimension);
+        assert_sampled_global_optimum(&f, 1e-9);
+        let mut xs = vec![0.3; 20];
+        let y = f.evaluate(&xs);
+        xs[10] = 0.9;
+        assert_eq!(f.evaluate(&xs), y);
+        assert_gradient(&f, &xs);
+
+        let f = Embedded::random_linear(Adjiman, dimension, 0);
+        assert!(f
+            .input_domain()
+            .iter()
+            .all(|i| i.min() == -1.0 && i.max() == 1.0));
+        assert_sampled_global_optimum(&f, 1e-9);
+        assert_gradient(&f, &[0.1; 20]);
+        assert_eq!(
+            f.project(&[0.1; 20]),
+            Embedded::random_linear(Adjiman, dimension, 0).project(&[0.1; 20])
+        );
+
+        // The minimum-norm pre-image of the optimum is out of the input domain for this seed.
+        let f = Embedded::random_linear(Adjiman, NonZeroUsize::new(3).unwrap(), 11);
+        assert_sampled_global_optimum(&f, 1e-9);
+    }
+
+    #[test]
+    fn variable_types_work() {
+        let discretized = Discretized::new(Ackley::new(NonZeroUsize::new(2).unwrap()))
+            .with_variable_type(0, VariableType::Levels(vec![-32.0, 0.0, 32.0]))
+            .with_policy(OffGridPolicy::Reject);
+
+        let f = Normalized::new(discretized.clone());
+        assert_eq!(
+            f.variable_types(),
+            vec![
+                VariableType::Levels(vec![0.0, 0.5, 1.0]),
+                VariableType::Continuous
+            ]
+        );
+        assert!(f.validate(&[0.5, 0.3]).is_ok());
+        assert_eq!(
+            f.validate(&[0.7, 0.3]),
+            Err(Error::OffGrid {
+                index: 0,
+                value: 0.7
+            })
+        );
+        assert!(f.validate(&[1.5, 0.3]).is_err());
+
+        let f = Shifted::new(discretized.clone(), vec![0.5, 0.0]);
+        assert_eq!(
+            f.variable_types()[0],
+            VariableType::Levels(vec![-31.5, 0.5])
+        );
+        assert!(f.validate(&[0.5, 3.0]).is_ok());
+        assert_eq!(
+            f.validate(&[0.4, 3.0]),
+            Err(Error::OffGrid {
+                index: 0,
+                value: 0.4
+            })
+        );
+
+        let f = Rotated::random(discretized.clone(), 0);
+        assert_eq!(f.variable_types(), vec![VariableType::Continuous; 2]);
+        assert!(f.validate(&[0.0, 0.0]).is_ok());
+        assert!(f.validate(&[0.3, 0.0]).is_err());
+
+        let f = Embedded::dummy(discretized, NonZeroUsize::new(3).unwrap());
+        assert_eq!(
+            f.variable_types(),
+            vec![
+                VariableType::Levels(vec![-32.0, 0.0, 32.0]),
+                VariableType::Continuous,
+                VariableType::Continuous
+            ]
+        );
+        assert!(f.validate(&[0.0, 3.0, 0.5]).is_ok());
+        assert!(f.validate(&[0.1, 3.0, 0.5]).is_err());
+    }
+
+    #[test]
+    fn random_orthogonal_matrix_works() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let m = random_orthogonal_matrix(5, &mut rng);
+        for i in 0..5 {
+            for j in 0..5 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((dot(&m[i], &m[j]) - expected).abs() < 1e-12);
+            }
+        }
+    }
+}
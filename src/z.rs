@@ -0,0 +1,98 @@
+use crate::{interval, Differentiable, GlobalOptimum, Interval, Objective, Optimum};
+use std::num::NonZeroUsize;
+
+/// Zakharov Function.
+///
+/// # References
+///
+/// - [Virtual Library of Simulation Experiments: Zakharov Function](https://www.sfu.ca/~ssurjano/zakharov.html)
+#[derive(Debug, Clone)]
+pub struct Zakharov {
+    input_domain: Vec<Interval>,
+}
+impl Zakharov {
+    /// Makes a new `Zakharov` instance.
+    pub fn new(dimension: NonZeroUsize) -> Self {
+        let input_domain = (0..dimension.get()).map(|_| interval(-5.0, 10.0)).collect();
+        Self { input_domain }
+    }
+}
+impl Zakharov {
+    fn weighted_sum(xs: &[f64]) -> f64 {
+        xs.iter()
+            .enumerate()
+            .map(|(i, &x)| 0.5 * (i + 1) as f64 * x)
+            .sum()
+    }
+}
+impl Objective for Zakharov {
+    type Output = f64;
+
+    fn input_domain(&self) -> &[Interval] {
+        &self.input_domain
+    }
+
+    fn evaluate(&self, xs: &[f64]) -> f64 {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let s = Self::weighted_sum(xs);
+        xs.iter().map(|&x| x * x).sum::<f64>() + s.powi(2) + s.powi(4)
+    }
+}
+impl GlobalOptimum for Zakharov {
+    fn global_optimum(&self) -> Option<Optimum> {
+        Some(Optimum::new(0.0, vec![vec![0.0; self.dimension().get()]]))
+    }
+}
+impl Differentiable for Zakharov {
+    fn gradient(&self, xs: &[f64]) -> Vec<f64> {
+        assert_eq!(xs.len(), self.dimension().get());
+
+        let s = Self::weighted_sum(xs);
+        let k = 2.0 * s + 4.0 * s.powi(3);
+        xs.iter()
+            .enumerate()
+            .map(|(i, &x)| 2.0 * x + k * 0.5 * (i + 1) as f64)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_global_optimum, assert_gradient, assert_reference_values};
+
+    #[test]
+    fn zakharov_works() {
+        let xs = [
+            [4.157006844510123, -2.6570151347965294, -4.363462629181937],
+            [8.016685508915836, -0.29254220125862496, 9.379891396126826],
+            [8.449894621414023, 0.6668385911827404, 1.9061444926885711],
+            [2.8010947688884587, 4.658330775815903, 3.934753576029557],
+            [3.3889159302308567, 4.30189203167893, 9.109318831359449],
+        ];
+        let ys = [
+            2669.4039328627205,
+            100532.64119943704,
+            3744.9279448813336,
+            20655.895834595147,
+            149903.7856796207,
+        ];
+
+        let f = Zakharov::new(NonZeroUsize::new(3).unwrap());
+        assert_reference_values(&f, &xs, &ys);
+    }
+
+    #[test]
+    fn global_optimums_work() {
+        assert_global_optimum(&Zakharov::new(NonZeroUsize::new(5).unwrap()));
+    }
+
+    #[test]
+    fn gradients_work() {
+        assert_gradient(
+            &Zakharov::new(NonZeroUsize::new(3).unwrap()),
+            &[0.3, -1.2, 2.5],
+        );
+    }
+}